@@ -10,12 +10,12 @@ use std::env;
 async fn main() -> Result<()> {
     let (owner, repo) = ("chenao", "gritea");
 
-    let cli =
-        Gritea::builder("git.chenaoxd.com")
-            .token(env::var("ACCESS_TOKEN").with_context(|| {
-                format!("get environment variable ACCESS_TOKEN failed")
-            })?)
-            .build()?;
+    let cli = Gritea::builder("git.chenaoxd.com")
+        .token(
+            env::var("ACCESS_TOKEN")
+                .context("get environment variable ACCESS_TOKEN failed")?,
+        )
+        .build()?;
 
     let user = cli.current_user().await?;
     println!("current_user: {:#?}", user);
@@ -3,25 +3,87 @@ use crate::{
     oauth::AccessToken,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum Auth {
     Token(String),
     OAuth2(AccessToken),
+    Basic { username: String, password: String },
     None,
 }
 
 impl Auth {
     pub fn headers(&self) -> Result<(String, String)> {
-        match &self {
-            &Auth::Token(token) => Ok((
+        match self {
+            Auth::Token(token) => Ok((
                 http::header::AUTHORIZATION.to_string(),
                 format!("token {}", token),
             )),
-            &Auth::OAuth2(oauth_token) => Ok((
+            Auth::OAuth2(oauth_token) => Ok((
                 http::header::AUTHORIZATION.to_string(),
                 format!("{:#?} {}", oauth_token.token_type, oauth_token.access_token),
             )),
-            &Auth::None => Err(Error::Unauthorized("client token not set".to_string())),
+            Auth::Basic { username, password } => Ok((
+                http::header::AUTHORIZATION.to_string(),
+                format!(
+                    "Basic {}",
+                    base64::encode(format!("{}:{}", username, password))
+                ),
+            )),
+            Auth::None => Err(Error::Unauthorized("client token not set".to_string())),
+        }
+    }
+}
+
+// keep credentials out of logs, `Config` and `Gritea` print the auth
+impl std::fmt::Debug for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = format_args!("<redacted>");
+
+        match self {
+            Auth::Token(_) => f.debug_tuple("Token").field(&redacted).finish(),
+            Auth::OAuth2(_) => f.debug_tuple("OAuth2").field(&redacted).finish(),
+            Auth::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &redacted)
+                .finish(),
+            Auth::None => f.write_str("None"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_auth_headers() {
+        let auth = Auth::Basic {
+            username: "foo".to_string(),
+            password: "bar".to_string(),
+        };
+
+        assert_eq!(
+            auth.headers().unwrap(),
+            (
+                "authorization".to_string(),
+                "Basic Zm9vOmJhcg==".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn debug_redacts_credentials() {
+        let auth = Auth::Basic {
+            username: "foo".to_string(),
+            password: "bar".to_string(),
+        };
+        let token = Auth::Token("secret".to_string());
+
+        assert_eq!(
+            format!("{:?}", auth),
+            r#"Basic { username: "foo", password: <redacted> }"#
+        );
+        assert_eq!(format!("{:?}", token), "Token(<redacted>)");
+    }
+}
@@ -9,6 +9,8 @@ pub struct GriteaBuilder {
     scheme: String,
     host: String,
     token: Auth,
+    sudo: Option<String>,
+    otp: Option<String>,
     cli: Option<Client>,
 }
 
@@ -19,6 +21,8 @@ impl GriteaBuilder {
             scheme: "https".to_string(),
             host: host.into(),
             token: Auth::None,
+            sudo: None,
+            otp: None,
             cli: None,
         }
     }
@@ -47,6 +51,31 @@ impl GriteaBuilder {
         self
     }
 
+    /// Switch to using basic authentication with username and password
+    pub fn basic_auth(
+        &mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> &mut Self {
+        self.token = Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        };
+        self
+    }
+
+    /// Act as another user, the token must belong to an admin
+    pub fn sudo(&mut self, username: impl Into<String>) -> &mut Self {
+        self.sudo = Some(username.into());
+        self
+    }
+
+    /// Send a one-time password, for accounts with two-factor authentication
+    pub fn otp(&mut self, code: impl Into<String>) -> &mut Self {
+        self.otp = Some(code.into());
+        self
+    }
+
     /// Use the specified reqwest client, avoid to establish new http connections
     pub fn cli(&mut self, cli: Client) -> &mut Self {
         self.cli = Some(cli);
@@ -61,12 +90,13 @@ impl GriteaBuilder {
             None => Client::new(),
         };
 
-        Ok(Gritea::new(
-            Config {
-                base_url,
-                token: self.token.clone(),
-            },
-            cli,
-        ))
+        let conf = Config {
+            base_url,
+            token: self.token.clone(),
+        };
+
+        Ok(Gritea::new(conf, cli)
+            .with_sudo(self.sudo.as_deref())
+            .with_otp(self.otp.as_deref()))
     }
 }
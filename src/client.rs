@@ -1,23 +1,94 @@
-use http::Method;
-use reqwest::{Client, RequestBuilder};
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use http::{Method, StatusCode};
+use reqwest::{
+    multipart::{Form, Part},
+    Body, Client, RequestBuilder,
+};
 use serde::de::DeserializeOwned;
 use std::sync::{Arc, RwLock};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
 use url::Url;
 
 use crate::{
+    auth::Auth,
     builder::GriteaBuilder,
     config::Config,
+    contents::{
+        ChangeFilesOptions, ContentsResponse, CreateFileOptions, DeleteFileOptions,
+        FileResponse, FilesResponse, UpdateFileOptions,
+    },
     error::{Error, Result},
+    git::{GitBlobResponse, GitTreeResponse, Reference, References},
     hook::{CreateHookOption, Hook},
+    issue::{
+        Comment, CreateIssueCommentOption, CreateIssueOption, CreateLabelOption,
+        CreateMilestoneOption, EditIssueCommentOption, EditIssueOption,
+        EditLabelOption, EditMilestoneOption, Issue, IssueLabelsOption, Label,
+        ListIssueCommentOption, ListIssueOption, ListMilestoneOption, Milestone,
+    },
+    key::{
+        CreateGPGKeyOption, CreateKeyOption, DeployKey, GPGKey, PublicKey,
+        VerifyGPGKeyOption,
+    },
+    oauth::{CreateOAuth2ApplicationOptions, OAuth2Application},
+    org::{
+        CreateOrgOption, CreateTeamOption, EditOrgOption, EditTeamOption, Organization,
+        Team, TeamSearchResult,
+    },
     pagination::Pagination,
-    repo::{CommitStatus, CreateStatusOption, Repository, SearchResult},
-    user::User,
+    pull::{
+        CreatePullRequestOption, CreatePullReviewOptions, DismissPullReviewOptions,
+        EditPullRequestOption, ListPullRequestsOption, MergePullRequestOption,
+        PullRequest, PullReview, PullReviewComment, PullReviewRequestOptions,
+        SubmitPullReviewOptions,
+    },
+    release::{
+        Attachment, CreateReleaseOption, EditAttachmentOptions, EditReleaseOption,
+        Release,
+    },
+    repo::{
+        AddCollaboratorOption, AnnotatedTag, ArchiveFormat, Branch, BranchProtection,
+        CombinedStatus, Commit, CommitStatus, Compare, CreateBranchProtectionOption,
+        CreateBranchRepoOption, CreateForkOption, CreatePushMirrorOption,
+        CreateRepoOption, CreateStatusOption, CreateTagOption,
+        CreateTagProtectionOption, EditBranchProtectionOption, EditRepoOption,
+        EditTagProtectionOption, GenerateRepoOption, ListCommitOptions,
+        ListStatusesOption, MigrateRepoOptions, PushMirror, RenameBranchRepoOption,
+        RepoCollaboratorPermission, Repository, SearchResult, Tag, TagProtection,
+        TransferRepoOption,
+    },
+    user::{
+        CreateAccessTokenOption, PersonalAccessToken, User, UserHeatmapData,
+        UserSearchResult,
+    },
 };
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Gritea {
     conf: Arc<RwLock<Config>>,
     cli: Client,
+    /// Overrides of the shared config for this client only
+    auth: Option<Auth>,
+    sudo: Option<String>,
+    otp: Option<String>,
+}
+
+// keep the one-time password out of logs, `Auth` redacts itself
+impl std::fmt::Debug for Gritea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Gritea")
+            .field("conf", &self.conf)
+            .field("cli", &self.cli)
+            .field("auth", &self.auth)
+            .field("sudo", &self.sudo)
+            .field(
+                "otp",
+                &self.otp.as_ref().map(|_| format_args!("<redacted>")),
+            )
+            .finish()
+    }
 }
 
 impl Gritea {
@@ -29,6 +100,9 @@ impl Gritea {
         Self {
             conf: Arc::new(RwLock::new(conf)),
             cli,
+            auth: None,
+            sudo: None,
+            otp: None,
         }
     }
 
@@ -45,18 +119,103 @@ impl Gritea {
     }
 
     pub fn headers(&self) -> Result<(String, String)> {
-        // TODO: auto refresh
-        self.r_conf()?.token.headers()
+        match &self.auth {
+            Some(auth) => auth.headers(),
+            // TODO: auto refresh
+            None => self.r_conf()?.token.headers(),
+        }
     }
 
     pub fn request(&self, method: Method, rel_url: &str) -> Result<RequestBuilder> {
-        let url = self.api_url(rel_url)?;
+        self.request_url(method, self.api_url(rel_url)?)
+    }
+
+    /// A client sharing the config and connections of this one, which
+    /// authenticates with `username` and `password` instead of the configured
+    /// auth, some apis (e.g. access tokens) require it
+    pub fn with_basic_auth(
+        &self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        let mut cli = self.clone();
+        cli.auth = Some(Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+        cli
+    }
+
+    /// A client sharing the config and connections of this one, which acts as
+    /// `username` for the requests sent through it (the token must belong to an
+    /// admin), or as the authenticated user itself if None
+    pub fn with_sudo(&self, username: Option<&str>) -> Self {
+        let mut cli = self.clone();
+        cli.sudo = username.map(ToString::to_string);
+        cli
+    }
+
+    /// A client sharing the config and connections of this one, which sends the
+    /// one-time password `code` for the requests sent through it, or none if None
+    pub fn with_otp(&self, code: Option<&str>) -> Self {
+        let mut cli = self.clone();
+        cli.otp = code.map(ToString::to_string);
+        cli
+    }
+
+    /// A request to the access tokens of the basic auth user, or to the `token`
+    /// among them
+    fn access_tokens_request(
+        &self,
+        method: Method,
+        token: Option<&str>,
+    ) -> Result<RequestBuilder> {
+        let auth = match &self.auth {
+            Some(auth) => auth.clone(),
+            None => self.r_conf()?.token,
+        };
+        let username = match auth {
+            Auth::Basic { username, .. } => username,
+            _ => {
+                return Err(Error::Unauthorized(
+                    "basic auth required, see `with_basic_auth`".to_string(),
+                ))
+            }
+        };
+        let rel_url = match token {
+            Some(token) => format!("users/{}/tokens/{}", username, token),
+            None => format!("users/{}/tokens", username),
+        };
+
+        // with sudo gitea would act as another user than the one in the path
+        self.with_sudo(None).request(method, &rel_url)
+    }
+
+    /// The sudo and one-time password headers, sent along with `headers`
+    fn override_headers(&self) -> Vec<(&'static str, &str)> {
+        let mut headers = vec![];
+        if let Some(sudo) = &self.sudo {
+            headers.push(("Sudo", sudo.as_str()));
+        }
+        if let Some(otp) = &self.otp {
+            headers.push(("X-Gitea-OTP", otp.as_str()));
+        }
+
+        headers
+    }
+
+    fn request_url(&self, method: Method, url: Url) -> Result<RequestBuilder> {
         let auth_header = self.headers()?;
 
-        Ok(self
+        let mut req = self
             .cli
             .request(method, url)
-            .header(auth_header.0, auth_header.1))
+            .header(auth_header.0, auth_header.1);
+        for (key, value) in self.override_headers() {
+            req = req.header(key, value);
+        }
+
+        Ok(req)
     }
 }
 
@@ -73,6 +232,172 @@ impl Gritea {
         resp_json(resp, "get user failed").await
     }
 
+    /// Get a user by its username
+    pub async fn get_user(&self, username: &str) -> Result<User> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}", username))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get user failed").await
+    }
+
+    /// Search users by keyword, or by id if `uid` is set
+    pub async fn search_users(
+        &self,
+        query: &str,
+        uid: Option<i64>,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let mut search_q = page.to_query().to_vec();
+        search_q.push(("q".to_string(), query.to_string()));
+        if let Some(uid) = uid {
+            search_q.push(("uid".to_string(), uid.to_string()));
+        }
+
+        let resp = self
+            .request(Method::GET, "users/search")?
+            .query(&search_q)
+            .send()
+            .await?;
+        let search_res: Result<UserSearchResult> =
+            resp_json(resp, "search user failed").await;
+
+        match search_res {
+            Err(err) => Err(err),
+            Ok(res) => {
+                if res.ok {
+                    Ok(res.data)
+                } else {
+                    Err(Error::GiteaError(serde_json::to_string(&res)?))
+                }
+            }
+        }
+    }
+
+    /// List the followers of a user
+    pub async fn list_followers(
+        &self,
+        username: &str,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/followers", username))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list followers of {} failed", username)).await
+    }
+
+    /// List the users a user is following
+    pub async fn list_following(
+        &self,
+        username: &str,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/following", username))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list following of {} failed", username)).await
+    }
+
+    /// Check if the current user is following a user
+    pub async fn is_following(&self, username: &str) -> Result<bool> {
+        let resp = self
+            .request(Method::GET, &format!("user/following/{}", username))?
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_success(resp, "check following failed").await?;
+
+        Ok(true)
+    }
+
+    /// Follow a user
+    pub async fn follow(&self, username: &str) -> Result<()> {
+        let resp = self
+            .request(Method::PUT, &format!("user/following/{}", username))?
+            .send()
+            .await?;
+
+        check_success(resp, "follow user failed").await
+    }
+
+    /// Unfollow a user
+    pub async fn unfollow(&self, username: &str) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("user/following/{}", username))?
+            .send()
+            .await?;
+
+        check_success(resp, "unfollow user failed").await
+    }
+
+    /// List the repos starred by a user
+    pub async fn list_user_starred(
+        &self,
+        username: &str,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/starred", username))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list starred repos of {} failed", username)).await
+    }
+
+    /// List the repos watched by a user
+    pub async fn list_user_subscriptions(
+        &self,
+        username: &str,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/subscriptions", username))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list watched repos of {} failed", username)).await
+    }
+
+    /// Get the contribution heatmap of a user
+    pub async fn get_user_heatmap(
+        &self,
+        username: &str,
+    ) -> Result<Vec<UserHeatmapData>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/heatmap", username))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get user heatmap failed").await
+    }
+
+    /// List the repos owned by a user
+    pub async fn list_user_repos(
+        &self,
+        username: &str,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("users/{}/repos", username))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list repos of {} failed", username)).await
+    }
+
     // ===============================================
     // Repository related apis
     // ===============================================
@@ -126,6 +451,164 @@ impl Gritea {
         }
     }
 
+    /// Create a repo owned by the current user
+    pub async fn create_repo(&self, opt: &CreateRepoOption) -> Result<Repository> {
+        let resp = self
+            .request(Method::POST, "user/repos")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create repo failed").await
+    }
+
+    /// Create a repo owned by an organization
+    pub async fn create_org_repo(
+        &self,
+        org: &str,
+        opt: &CreateRepoOption,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(Method::POST, &format!("orgs/{}/repos", org))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create org repo failed").await
+    }
+
+    /// Edit the settings of a repo
+    pub async fn edit_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &EditRepoOption,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(Method::PATCH, &format!("repos/{}/{}", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit repo failed").await
+    }
+
+    /// Delete a repo
+    pub async fn delete_repo(&self, owner: &str, repo: &str) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("repos/{}/{}", owner, repo))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete repo failed").await
+    }
+
+    /// Transfer a repo to another user or organization, the transfer may need
+    /// to be accepted by the new owner
+    pub async fn transfer_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &TransferRepoOption,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/transfer", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "transfer repo failed").await
+    }
+
+    /// Accept a pending transfer of a repo
+    pub async fn accept_repo_transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/transfer/accept", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "accept repo transfer failed").await
+    }
+
+    /// Reject a pending transfer of a repo
+    pub async fn reject_repo_transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/transfer/reject", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "reject repo transfer failed").await
+    }
+
+    /// Create a repo from a template repo
+    pub async fn generate_from_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        opt: &GenerateRepoOption,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/generate", template_owner, template_repo),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "generate repo from template failed").await
+    }
+
+    /// Fork a repo
+    pub async fn fork_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateForkOption,
+    ) -> Result<Repository> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/forks", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "fork repo failed").await
+    }
+
+    /// List the forks of a repo
+    pub async fn list_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/forks", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list forks of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
     /// Create a commit status
     pub async fn create_status(
         &self,
@@ -194,28 +677,2823 @@ impl Gritea {
         )
         .await
     }
-}
 
-pub async fn resp_json<T>(resp: reqwest::Response, err_mes: &str) -> Result<T>
-where
-    T: DeserializeOwned,
-{
-    if !resp.status().is_success() {
-        Err(Error::GiteaError(format!(
-            "{}: [{}] {}",
-            err_mes,
-            resp.status(),
-            resp.text().await?
-        )))
-    } else {
-        Ok(resp.json::<T>().await?)
-    }
-}
+    // ===============================================
+    // Issue related apis
+    // ===============================================
 
-pub async fn check_success(resp: reqwest::Response, err_mes: &str) -> Result<()> {
-    if !resp.status().is_success() {
-        Err(Error::GiteaError(format!(
-            "{}: [{}] {}",
+    /// List issues of a repo, filtered by `opt`
+    pub async fn list_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ListIssueOption,
+        page: Pagination,
+    ) -> Result<Vec<Issue>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/issues", owner, repo))?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list issues of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get an issue by its index
+    pub async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<Issue> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/issues/{}", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get issue failed").await
+    }
+
+    /// Create an issue
+    pub async fn create_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateIssueOption,
+    ) -> Result<Issue> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/issues", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create issue failed").await
+    }
+
+    /// Edit an issue, set `opt.state` to close or reopen it
+    pub async fn edit_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &EditIssueOption,
+    ) -> Result<Issue> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/issues/{}", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit issue failed").await
+    }
+
+    /// List all comments on an issue or a pull request
+    pub async fn list_issue_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &ListIssueCommentOption,
+    ) -> Result<Vec<Comment>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/issues/{}/comments", owner, repo, index),
+            )?
+            .query(&opt.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list comments of issue {}/{}#{} failed", owner, repo, index),
+        )
+        .await
+    }
+
+    /// List all comments in a repo
+    pub async fn list_repo_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ListIssueCommentOption,
+        page: Pagination,
+    ) -> Result<Vec<Comment>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/issues/comments", owner, repo),
+            )?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list comments of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Add a comment to an issue or a pull request
+    pub async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &CreateIssueCommentOption,
+    ) -> Result<Comment> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/issues/{}/comments", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create comment failed").await
+    }
+
+    /// Edit a comment
+    pub async fn edit_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        opt: &EditIssueCommentOption,
+    ) -> Result<Comment> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/issues/comments/{}", owner, repo, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit comment failed").await
+    }
+
+    /// Delete a comment
+    pub async fn delete_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/issues/comments/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete comment failed").await
+    }
+
+    // ===============================================
+    // Pull request related apis
+    // ===============================================
+
+    /// List pull requests of a repo, filtered by `opt`
+    pub async fn list_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ListPullRequestsOption,
+        page: Pagination,
+    ) -> Result<Vec<PullRequest>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/pulls", owner, repo))?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list pull requests of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a pull request by its index
+    pub async fn get_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<PullRequest> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get pull request failed").await
+    }
+
+    /// Create a pull request
+    pub async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreatePullRequestOption,
+    ) -> Result<PullRequest> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/pulls", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create pull request failed").await
+    }
+
+    /// Edit a pull request
+    pub async fn edit_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &EditPullRequestOption,
+    ) -> Result<PullRequest> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/pulls/{}", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit pull request failed").await
+    }
+
+    /// Merge a pull request
+    pub async fn merge_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &MergePullRequestOption,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/pulls/{}/merge", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        check_success(resp, "merge pull request failed").await
+    }
+
+    /// Check if a pull request has been merged
+    pub async fn is_pull_request_merged(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<bool> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}/merge", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_success(resp, "check pull request merged failed").await?;
+
+        Ok(true)
+    }
+
+    /// Get the `.diff` representation of a pull request
+    pub async fn get_pull_request_diff(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<String> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}.diff", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        resp_text(resp, "get pull request diff failed").await
+    }
+
+    /// Get the `.patch` representation of a pull request
+    pub async fn get_pull_request_patch(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<String> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}.patch", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        resp_text(resp, "get pull request patch failed").await
+    }
+
+    /// List reviews of a pull request
+    pub async fn list_pull_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        page: Pagination,
+    ) -> Result<Vec<PullReview>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}/reviews", owner, repo, index),
+            )?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list reviews of pull {}/{}#{} failed", owner, repo, index),
+        )
+        .await
+    }
+
+    /// Get a review of a pull request
+    pub async fn get_pull_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        id: i64,
+    ) -> Result<PullReview> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/pulls/{}/reviews/{}", owner, repo, index, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get pull review failed").await
+    }
+
+    /// Create a review on a pull request
+    pub async fn create_pull_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &CreatePullReviewOptions,
+    ) -> Result<PullReview> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/pulls/{}/reviews", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create pull review failed").await
+    }
+
+    /// Submit a pending review
+    pub async fn submit_pull_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        id: i64,
+        opt: &SubmitPullReviewOptions,
+    ) -> Result<PullReview> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/pulls/{}/reviews/{}", owner, repo, index, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "submit pull review failed").await
+    }
+
+    /// Dismiss a review
+    pub async fn dismiss_pull_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        id: i64,
+        opt: &DismissPullReviewOptions,
+    ) -> Result<PullReview> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!(
+                    "repos/{}/{}/pulls/{}/reviews/{}/dismissals",
+                    owner, repo, index, id
+                ),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "dismiss pull review failed").await
+    }
+
+    /// Delete a pending review
+    pub async fn delete_pull_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/pulls/{}/reviews/{}", owner, repo, index, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete pull review failed").await
+    }
+
+    /// List the line comments of a review
+    pub async fn list_pull_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        id: i64,
+    ) -> Result<Vec<PullReviewComment>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!(
+                    "repos/{}/{}/pulls/{}/reviews/{}/comments",
+                    owner, repo, index, id
+                ),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "list pull review comments failed").await
+    }
+
+    /// Request reviews from users for a pull request
+    pub async fn create_review_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &PullReviewRequestOptions,
+    ) -> Result<Vec<PullReview>> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!(
+                    "repos/{}/{}/pulls/{}/requested_reviewers",
+                    owner, repo, index
+                ),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create review requests failed").await
+    }
+
+    /// Cancel review requests for a pull request
+    pub async fn delete_review_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &PullReviewRequestOptions,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!(
+                    "repos/{}/{}/pulls/{}/requested_reviewers",
+                    owner, repo, index
+                ),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        check_success(resp, "delete review requests failed").await
+    }
+
+    // ===============================================
+    // Label related apis
+    // ===============================================
+
+    /// List labels of a repo
+    pub async fn list_repo_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<Label>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/labels", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list labels of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a label of a repo
+    pub async fn get_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<Label> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/labels/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get label failed").await
+    }
+
+    /// Create a label in a repo
+    pub async fn create_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateLabelOption,
+    ) -> Result<Label> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/labels", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create label failed").await
+    }
+
+    /// Edit a label of a repo
+    pub async fn edit_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        opt: &EditLabelOption,
+    ) -> Result<Label> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/labels/{}", owner, repo, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit label failed").await
+    }
+
+    /// Delete a label of a repo
+    pub async fn delete_repo_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/labels/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete label failed").await
+    }
+
+    /// List labels of an organization
+    pub async fn list_org_labels(
+        &self,
+        org: &str,
+        page: Pagination,
+    ) -> Result<Vec<Label>> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/labels", org))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list labels of org {} failed", org)).await
+    }
+
+    /// Create a label in an organization
+    pub async fn create_org_label(
+        &self,
+        org: &str,
+        opt: &CreateLabelOption,
+    ) -> Result<Label> {
+        let resp = self
+            .request(Method::POST, &format!("orgs/{}/labels", org))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create org label failed").await
+    }
+
+    /// Edit a label of an organization
+    pub async fn edit_org_label(
+        &self,
+        org: &str,
+        id: i64,
+        opt: &EditLabelOption,
+    ) -> Result<Label> {
+        let resp = self
+            .request(Method::PATCH, &format!("orgs/{}/labels/{}", org, id))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit org label failed").await
+    }
+
+    /// Delete a label of an organization
+    pub async fn delete_org_label(&self, org: &str, id: i64) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("orgs/{}/labels/{}", org, id))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete org label failed").await
+    }
+
+    /// List labels of an issue
+    pub async fn list_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<Vec<Label>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/issues/{}/labels", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "list issue labels failed").await
+    }
+
+    /// Add labels to an issue, returns all the labels of the issue
+    pub async fn add_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &IssueLabelsOption,
+    ) -> Result<Vec<Label>> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/issues/{}/labels", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "add issue labels failed").await
+    }
+
+    /// Replace all the labels of an issue
+    pub async fn replace_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        opt: &IssueLabelsOption,
+    ) -> Result<Vec<Label>> {
+        let resp = self
+            .request(
+                Method::PUT,
+                &format!("repos/{}/{}/issues/{}/labels", owner, repo, index),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "replace issue labels failed").await
+    }
+
+    /// Remove a label from an issue
+    pub async fn delete_issue_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+        label: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/issues/{}/labels/{}", owner, repo, index, label),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete issue label failed").await
+    }
+
+    /// Remove all labels from an issue
+    pub async fn clear_issue_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        index: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/issues/{}/labels", owner, repo, index),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "clear issue labels failed").await
+    }
+
+    // ===============================================
+    // Milestone related apis
+    // ===============================================
+
+    /// List milestones of a repo, filtered by `opt`
+    pub async fn list_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ListMilestoneOption,
+        page: Pagination,
+    ) -> Result<Vec<Milestone>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/milestones", owner, repo))?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list milestones of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a milestone
+    pub async fn get_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<Milestone> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/milestones/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get milestone failed").await
+    }
+
+    /// Create a milestone
+    pub async fn create_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateMilestoneOption,
+    ) -> Result<Milestone> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/milestones", owner, repo),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create milestone failed").await
+    }
+
+    /// Edit a milestone
+    pub async fn edit_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        opt: &EditMilestoneOption,
+    ) -> Result<Milestone> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/milestones/{}", owner, repo, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit milestone failed").await
+    }
+
+    /// Delete a milestone
+    pub async fn delete_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/milestones/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete milestone failed").await
+    }
+
+    // ===============================================
+    // Branch related apis
+    // ===============================================
+
+    /// List branches of a repo
+    pub async fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<Branch>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/branches", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list branches of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a branch
+    pub async fn get_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Branch> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/branches/{}", owner, repo, branch),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get branch failed").await
+    }
+
+    /// Create a branch from an old branch or ref
+    pub async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateBranchRepoOption,
+    ) -> Result<Branch> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/branches", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create branch failed").await
+    }
+
+    /// Delete a branch
+    pub async fn delete_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/branches/{}", owner, repo, branch),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete branch failed").await
+    }
+
+    /// Rename a branch
+    pub async fn rename_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        opt: &RenameBranchRepoOption,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/branches/{}", owner, repo, branch),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        check_success(resp, "rename branch failed").await
+    }
+
+    // ===============================================
+    // Branch protection related apis
+    // ===============================================
+
+    /// List branch protections of a repo
+    pub async fn list_branch_protections(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<BranchProtection>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/branch_protections", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list branch protections of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a branch protection by its rule name
+    pub async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<BranchProtection> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!(
+                    "repos/{}/{}/branch_protections/{}",
+                    owner,
+                    repo,
+                    encode_segment(name)
+                ),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get branch protection failed").await
+    }
+
+    /// Create a branch protection
+    pub async fn create_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateBranchProtectionOption,
+    ) -> Result<BranchProtection> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/branch_protections", owner, repo),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create branch protection failed").await
+    }
+
+    /// Edit a branch protection
+    pub async fn edit_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        opt: &EditBranchProtectionOption,
+    ) -> Result<BranchProtection> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!(
+                    "repos/{}/{}/branch_protections/{}",
+                    owner,
+                    repo,
+                    encode_segment(name)
+                ),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit branch protection failed").await
+    }
+
+    /// Delete a branch protection
+    pub async fn delete_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!(
+                    "repos/{}/{}/branch_protections/{}",
+                    owner,
+                    repo,
+                    encode_segment(name)
+                ),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete branch protection failed").await
+    }
+
+    // ===============================================
+    // Repository contents related apis
+    // ===============================================
+
+    /// Get the metadata and content of a file, `ref_` defaults to the default
+    /// branch
+    pub async fn get_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: Option<&str>,
+    ) -> Result<ContentsResponse> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+            )?
+            .query(&ref_query(ref_))
+            .send()
+            .await?;
+
+        resp_json(resp, "get contents failed").await
+    }
+
+    /// List the metadata of all entries in a directory
+    pub async fn list_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: Option<&str>,
+    ) -> Result<Vec<ContentsResponse>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+            )?
+            .query(&ref_query(ref_))
+            .send()
+            .await?;
+
+        resp_json(resp, "list contents failed").await
+    }
+
+    /// Create a file
+    pub async fn create_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        opt: &CreateFileOptions,
+    ) -> Result<FileResponse> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create file failed").await
+    }
+
+    /// Update a file
+    pub async fn update_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        opt: &UpdateFileOptions,
+    ) -> Result<FileResponse> {
+        let resp = self
+            .request(
+                Method::PUT,
+                &format!("repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "update file failed").await
+    }
+
+    /// Delete a file
+    pub async fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        opt: &DeleteFileOptions,
+    ) -> Result<FileResponse> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/contents/{}", owner, repo, encode_path(path)),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "delete file failed").await
+    }
+
+    /// Create, update or delete multiple files in one commit
+    pub async fn change_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ChangeFilesOptions,
+    ) -> Result<FilesResponse> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/contents", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "change files failed").await
+    }
+
+    /// Download a file as it is stored in git, `ref_` defaults to the default
+    /// branch
+    pub async fn get_raw_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: Option<&str>,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/raw/{}", owner, repo, encode_path(path)),
+            )?
+            .query(&ref_query(ref_))
+            .send()
+            .await?;
+
+        resp_stream(resp, "get raw file failed").await
+    }
+
+    /// Download an archive of a repo at the given branch, tag or commit
+    pub async fn download_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        format: ArchiveFormat,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!(
+                    "repos/{}/{}/archive/{}.{}",
+                    owner,
+                    repo,
+                    encode_path(ref_),
+                    format
+                ),
+            )?
+            .send()
+            .await?;
+
+        resp_stream(resp, "download archive failed").await
+    }
+
+    // ===============================================
+    // Release related apis
+    // ===============================================
+
+    /// List releases of a repo
+    pub async fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<Release>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/releases", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list releases of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a release
+    pub async fn get_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<Release> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/releases/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get release failed").await
+    }
+
+    /// Get the release of a tag
+    pub async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> Result<Release> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/releases/tags/{}", owner, repo, tag),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get release by tag failed").await
+    }
+
+    /// Get the most recent non-prerelease, non-draft release
+    pub async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<Release> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/releases/latest", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get latest release failed").await
+    }
+
+    /// Create a release
+    pub async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateReleaseOption,
+    ) -> Result<Release> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/releases", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create release failed").await
+    }
+
+    /// Edit a release
+    pub async fn edit_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        opt: &EditReleaseOption,
+    ) -> Result<Release> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/releases/{}", owner, repo, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit release failed").await
+    }
+
+    /// Delete a release, the tag is kept
+    pub async fn delete_release(&self, owner: &str, repo: &str, id: i64) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/releases/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete release failed").await
+    }
+
+    /// List the attachments of a release
+    pub async fn list_release_attachments(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<Vec<Attachment>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/releases/{}/assets", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "list release attachments failed").await
+    }
+
+    /// Upload an attachment to a release, the content of `reader` (e.g. a
+    /// `tokio::fs::File`) is streamed instead of being loaded into memory
+    pub async fn create_release_attachment<R>(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        name: &str,
+        reader: R,
+    ) -> Result<Attachment>
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        let part = Part::stream(Body::wrap_stream(ReaderStream::new(reader)))
+            .file_name(name.to_string());
+
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/releases/{}/assets", owner, repo, id),
+            )?
+            .query(&[("name", name)])
+            .multipart(Form::new().part("attachment", part))
+            .send()
+            .await?;
+
+        resp_json(resp, "create release attachment failed").await
+    }
+
+    /// Rename an attachment of a release
+    pub async fn edit_release_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        attachment_id: i64,
+        opt: &EditAttachmentOptions,
+    ) -> Result<Attachment> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!(
+                    "repos/{}/{}/releases/{}/assets/{}",
+                    owner, repo, id, attachment_id
+                ),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit release attachment failed").await
+    }
+
+    /// Download an attachment from its `browser_download_url`, which is served
+    /// outside the api, so no credentials are sent along
+    pub async fn download_release_attachment(
+        &self,
+        attachment: &Attachment,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let resp = self
+            .cli
+            .get(Url::parse(&attachment.browser_download_url)?)
+            .send()
+            .await?;
+
+        resp_stream(resp, "download release attachment failed").await
+    }
+
+    /// Delete an attachment of a release
+    pub async fn delete_release_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        attachment_id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!(
+                    "repos/{}/{}/releases/{}/assets/{}",
+                    owner, repo, id, attachment_id
+                ),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete release attachment failed").await
+    }
+
+    // ===============================================
+    // Tag related apis
+    // ===============================================
+
+    /// List tags of a repo
+    pub async fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<Tag>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/tags", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list tags of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a tag by its name
+    pub async fn get_tag(&self, owner: &str, repo: &str, tag: &str) -> Result<Tag> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/tags/{}", owner, repo, tag),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get tag failed").await
+    }
+
+    /// Get the tag object of an annotated tag by its sha
+    pub async fn get_annotated_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<AnnotatedTag> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/git/tags/{}", owner, repo, sha),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get annotated tag failed").await
+    }
+
+    /// Create a lightweight tag, or an annotated one if `opt.message` is set
+    pub async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateTagOption,
+    ) -> Result<Tag> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/tags", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create tag failed").await
+    }
+
+    /// Delete a tag by its name
+    pub async fn delete_tag(&self, owner: &str, repo: &str, tag: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/tags/{}", owner, repo, tag),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete tag failed").await
+    }
+
+    /// List tag protections of a repo
+    pub async fn list_tag_protections(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<TagProtection>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/tag_protections", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list tag protections of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a tag protection
+    pub async fn get_tag_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<TagProtection> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/tag_protections/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get tag protection failed").await
+    }
+
+    /// Create a tag protection
+    pub async fn create_tag_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateTagProtectionOption,
+    ) -> Result<TagProtection> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/tag_protections", owner, repo),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create tag protection failed").await
+    }
+
+    /// Edit a tag protection
+    pub async fn edit_tag_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+        opt: &EditTagProtectionOption,
+    ) -> Result<TagProtection> {
+        let resp = self
+            .request(
+                Method::PATCH,
+                &format!("repos/{}/{}/tag_protections/{}", owner, repo, id),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit tag protection failed").await
+    }
+
+    /// Delete a tag protection
+    pub async fn delete_tag_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/tag_protections/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete tag protection failed").await
+    }
+
+    // ===============================================
+    // Commit related apis
+    // ===============================================
+
+    /// List commits of a repo, filtered by `opt`
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &ListCommitOptions,
+        page: Pagination,
+    ) -> Result<Vec<Commit>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/commits", owner, repo))?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list commits of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a single commit, `sha` can also be a branch or tag name
+    pub async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<Commit> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/git/commits/{}", owner, repo, sha),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get commit failed").await
+    }
+
+    /// Compare two refs, the result contains the commits of `base...head`
+    pub async fn compare(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<Compare> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/compare/{}...{}", owner, repo, base, head),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "compare failed").await
+    }
+
+    // ===============================================
+    // Commit status related apis
+    // ===============================================
+
+    /// List the statuses of a commit, `ref_` can be a sha, branch or tag
+    pub async fn list_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        opt: &ListStatusesOption,
+        page: Pagination,
+    ) -> Result<Vec<CommitStatus>> {
+        let mut query = opt.to_query();
+        query.extend(page.to_query());
+
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/commits/{}/statuses", owner, repo, ref_),
+            )?
+            .query(&query)
+            .send()
+            .await?;
+
+        resp_json(resp, "list commit statuses failed").await
+    }
+
+    /// Get the combined status of a commit, `ref_` can be a sha, branch or tag
+    pub async fn get_combined_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<CombinedStatus> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/commits/{}/status", owner, repo, ref_),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get combined status failed").await
+    }
+
+    // ===============================================
+    // Git data related apis
+    // ===============================================
+
+    /// List the refs of a repo, optionally only those starting with `prefix`
+    /// (e.g. `heads` or `tags/v1`), a missing repo is only reported as an error
+    /// without `prefix`
+    pub async fn list_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        prefix: Option<&str>,
+    ) -> Result<Vec<Reference>> {
+        let rel_url = match prefix {
+            Some(prefix) => format!("repos/{}/{}/git/refs/{}", owner, repo, prefix),
+            None => format!("repos/{}/{}/git/refs", owner, repo),
+        };
+        let resp = self.request(Method::GET, &rel_url)?.send().await?;
+
+        // gitea answers 404 when no ref matches the prefix, note that a missing
+        // repo then also looks like no refs
+        if prefix.is_some() && resp.status() == StatusCode::NOT_FOUND {
+            return Ok(vec![]);
+        }
+        let refs: References = resp_json(
+            resp,
+            &format!("list refs of repo {}/{} failed", owner, repo),
+        )
+        .await?;
+
+        Ok(refs.into())
+    }
+
+    /// Get a tree by its sha, check `truncated` of the response to know
+    /// whether there are more entries on the next page
+    pub async fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        recursive: bool,
+        page: Pagination,
+    ) -> Result<GitTreeResponse> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/git/trees/{}", owner, repo, sha),
+            )?
+            .query(&[
+                ("recursive", recursive.to_string()),
+                ("page", page.page().to_string()),
+                ("per_page", page.limit().to_string()),
+            ])
+            .send()
+            .await?;
+
+        resp_json(resp, "get tree failed").await
+    }
+
+    /// Get a blob by its sha
+    pub async fn get_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<GitBlobResponse> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/git/blobs/{}", owner, repo, sha),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get blob failed").await
+    }
+
+    // ===============================================
+    // Migration and mirror related apis
+    // ===============================================
+
+    /// Migrate a repo from another service, or mirror it if `opt.mirror` is set
+    pub async fn migrate_repo(&self, opt: &MigrateRepoOptions) -> Result<Repository> {
+        let resp = self
+            .request(Method::POST, "repos/migrate")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "migrate repo failed").await
+    }
+
+    /// Sync a pull mirror with its remote
+    pub async fn mirror_sync(&self, owner: &str, repo: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/mirror-sync", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "mirror sync failed").await
+    }
+
+    /// List the push mirrors of a repo
+    pub async fn list_push_mirrors(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<PushMirror>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/push_mirrors", owner, repo),
+            )?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list push mirrors of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Add a push mirror to a repo
+    pub async fn add_push_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreatePushMirrorOption,
+    ) -> Result<PushMirror> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/push_mirrors", owner, repo),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "add push mirror failed").await
+    }
+
+    /// Delete a push mirror by its remote name
+    pub async fn delete_push_mirror(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/push_mirrors/{}", owner, repo, name),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete push mirror failed").await
+    }
+
+    /// Sync all the push mirrors of a repo
+    pub async fn push_mirrors_sync(&self, owner: &str, repo: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::POST,
+                &format!("repos/{}/{}/push_mirrors-sync", owner, repo),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "push mirrors sync failed").await
+    }
+
+    // ===============================================
+    // Collaborator related apis
+    // ===============================================
+
+    /// List the collaborators of a repo
+    pub async fn list_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/collaborators", owner, repo),
+            )?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list collaborators of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Check if a user is a collaborator of a repo
+    pub async fn is_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<bool> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/collaborators/{}", owner, repo, username),
+            )?
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_success(resp, "check collaborator failed").await?;
+
+        Ok(true)
+    }
+
+    /// Add a user as a collaborator of a repo, or change its permission
+    pub async fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        opt: &AddCollaboratorOption,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::PUT,
+                &format!("repos/{}/{}/collaborators/{}", owner, repo, username),
+            )?
+            .json(opt)
+            .send()
+            .await?;
+
+        check_success(resp, "add collaborator failed").await
+    }
+
+    /// Remove a collaborator from a repo
+    pub async fn delete_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/collaborators/{}", owner, repo, username),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete collaborator failed").await
+    }
+
+    /// Get the effective permission of a user on a repo
+    pub async fn get_repo_permissions(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<RepoCollaboratorPermission> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!(
+                    "repos/{}/{}/collaborators/{}/permission",
+                    owner, repo, username
+                ),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get repo permissions failed").await
+    }
+
+    // ===============================================
+    // Organization related apis
+    // ===============================================
+
+    /// List the organizations of the current user
+    pub async fn list_my_orgs(&self, page: Pagination) -> Result<Vec<Organization>> {
+        let resp = self
+            .request(Method::GET, "user/orgs")?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list my orgs failed").await
+    }
+
+    /// List all the organizations visible to the current user
+    pub async fn list_orgs(&self, page: Pagination) -> Result<Vec<Organization>> {
+        let resp = self
+            .request(Method::GET, "orgs")?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list orgs failed").await
+    }
+
+    /// Get an organization
+    pub async fn get_org(&self, org: &str) -> Result<Organization> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}", org))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get org failed").await
+    }
+
+    /// Create an organization
+    pub async fn create_org(&self, opt: &CreateOrgOption) -> Result<Organization> {
+        let resp = self.request(Method::POST, "orgs")?.json(opt).send().await?;
+
+        resp_json(resp, "create org failed").await
+    }
+
+    /// Edit an organization
+    pub async fn edit_org(
+        &self,
+        org: &str,
+        opt: &EditOrgOption,
+    ) -> Result<Organization> {
+        let resp = self
+            .request(Method::PATCH, &format!("orgs/{}", org))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit org failed").await
+    }
+
+    /// Delete an organization
+    pub async fn delete_org(&self, org: &str) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("orgs/{}", org))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete org failed").await
+    }
+
+    /// List the repos of an organization
+    pub async fn list_org_repos(
+        &self,
+        org: &str,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/repos", org))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list repos of org {} failed", org)).await
+    }
+
+    /// List the members of an organization
+    pub async fn list_org_members(
+        &self,
+        org: &str,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/members", org))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list members of org {} failed", org)).await
+    }
+
+    /// Check if a user is a member of an organization
+    ///
+    /// If the authenticated user is not a member of the organization, gitea
+    /// redirects to the public membership check, which is followed, so only
+    /// public membership is reported in that case (see `is_public_org_member`)
+    pub async fn is_org_member(&self, org: &str, username: &str) -> Result<bool> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/members/{}", org, username))?
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_success(resp, "check org member failed").await?;
+
+        Ok(true)
+    }
+
+    /// Remove a member from an organization
+    pub async fn delete_org_member(&self, org: &str, username: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("orgs/{}/members/{}", org, username),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete org member failed").await
+    }
+
+    /// List the public members of an organization
+    pub async fn list_public_org_members(
+        &self,
+        org: &str,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/public_members", org))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list public members of org {} failed", org)).await
+    }
+
+    /// Check if a user is a public member of an organization
+    pub async fn is_public_org_member(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<bool> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("orgs/{}/public_members/{}", org, username),
+            )?
+            .send()
+            .await?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        check_success(resp, "check public org member failed").await?;
+
+        Ok(true)
+    }
+
+    /// Make the membership of a user public
+    pub async fn publicize_org_member(&self, org: &str, username: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::PUT,
+                &format!("orgs/{}/public_members/{}", org, username),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "publicize org member failed").await
+    }
+
+    /// Hide the membership of a user
+    pub async fn conceal_org_member(&self, org: &str, username: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("orgs/{}/public_members/{}", org, username),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "conceal org member failed").await
+    }
+
+    // ===============================================
+    // Team related apis
+    // ===============================================
+
+    /// List the teams of an organization
+    pub async fn list_org_teams(
+        &self,
+        org: &str,
+        page: Pagination,
+    ) -> Result<Vec<Team>> {
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/teams", org))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list teams of org {} failed", org)).await
+    }
+
+    /// Search the teams of an organization by name
+    pub async fn search_org_teams(
+        &self,
+        org: &str,
+        query: &str,
+        page: Pagination,
+    ) -> Result<Vec<Team>> {
+        let mut search_q = page.to_query().to_vec();
+        search_q.push(("q".to_string(), query.to_string()));
+
+        let resp = self
+            .request(Method::GET, &format!("orgs/{}/teams/search", org))?
+            .query(&search_q)
+            .send()
+            .await?;
+        let search_res: Result<TeamSearchResult> =
+            resp_json(resp, "search team failed").await;
+
+        match search_res {
+            Err(err) => Err(err),
+            Ok(res) => {
+                if res.ok {
+                    Ok(res.data)
+                } else {
+                    Err(Error::GiteaError(serde_json::to_string(&res)?))
+                }
+            }
+        }
+    }
+
+    /// Get a team
+    pub async fn get_team(&self, id: i64) -> Result<Team> {
+        let resp = self
+            .request(Method::GET, &format!("teams/{}", id))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get team failed").await
+    }
+
+    /// Create a team in an organization
+    pub async fn create_team(&self, org: &str, opt: &CreateTeamOption) -> Result<Team> {
+        let resp = self
+            .request(Method::POST, &format!("orgs/{}/teams", org))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create team failed").await
+    }
+
+    /// Edit a team
+    pub async fn edit_team(&self, id: i64, opt: &EditTeamOption) -> Result<Team> {
+        let resp = self
+            .request(Method::PATCH, &format!("teams/{}", id))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "edit team failed").await
+    }
+
+    /// Delete a team
+    pub async fn delete_team(&self, id: i64) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("teams/{}", id))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete team failed").await
+    }
+
+    /// List the members of a team
+    pub async fn list_team_members(
+        &self,
+        id: i64,
+        page: Pagination,
+    ) -> Result<Vec<User>> {
+        let resp = self
+            .request(Method::GET, &format!("teams/{}/members", id))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list members of team {} failed", id)).await
+    }
+
+    /// Add a user to a team
+    pub async fn add_team_member(&self, id: i64, username: &str) -> Result<()> {
+        let resp = self
+            .request(Method::PUT, &format!("teams/{}/members/{}", id, username))?
+            .send()
+            .await?;
+
+        check_success(resp, "add team member failed").await
+    }
+
+    /// Remove a user from a team
+    pub async fn delete_team_member(&self, id: i64, username: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("teams/{}/members/{}", id, username),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete team member failed").await
+    }
+
+    /// List the repos a team has access to
+    pub async fn list_team_repos(
+        &self,
+        id: i64,
+        page: Pagination,
+    ) -> Result<Vec<Repository>> {
+        let resp = self
+            .request(Method::GET, &format!("teams/{}/repos", id))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, &format!("list repos of team {} failed", id)).await
+    }
+
+    /// Give a team access to a repo of its organization
+    pub async fn add_team_repo(&self, id: i64, org: &str, repo: &str) -> Result<()> {
+        let resp = self
+            .request(Method::PUT, &format!("teams/{}/repos/{}/{}", id, org, repo))?
+            .send()
+            .await?;
+
+        check_success(resp, "add team repo failed").await
+    }
+
+    /// Revoke the access of a team to a repo
+    pub async fn delete_team_repo(&self, id: i64, org: &str, repo: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("teams/{}/repos/{}/{}", id, org, repo),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete team repo failed").await
+    }
+
+    // ===============================================
+    // Key related apis
+    // ===============================================
+
+    /// List the ssh keys of the current user
+    pub async fn list_my_public_keys(
+        &self,
+        page: Pagination,
+    ) -> Result<Vec<PublicKey>> {
+        let resp = self
+            .request(Method::GET, "user/keys")?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list public keys failed").await
+    }
+
+    /// Get a ssh key of the current user
+    pub async fn get_public_key(&self, id: i64) -> Result<PublicKey> {
+        let resp = self
+            .request(Method::GET, &format!("user/keys/{}", id))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get public key failed").await
+    }
+
+    /// Add a ssh key to the current user
+    pub async fn create_public_key(&self, opt: &CreateKeyOption) -> Result<PublicKey> {
+        let resp = self
+            .request(Method::POST, "user/keys")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create public key failed").await
+    }
+
+    /// Delete a ssh key of the current user
+    pub async fn delete_public_key(&self, id: i64) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("user/keys/{}", id))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete public key failed").await
+    }
+
+    /// List the GPG keys of the current user
+    pub async fn list_my_gpg_keys(&self, page: Pagination) -> Result<Vec<GPGKey>> {
+        let resp = self
+            .request(Method::GET, "user/gpg_keys")?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list gpg keys failed").await
+    }
+
+    /// Get a GPG key of the current user
+    pub async fn get_gpg_key(&self, id: i64) -> Result<GPGKey> {
+        let resp = self
+            .request(Method::GET, &format!("user/gpg_keys/{}", id))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get gpg key failed").await
+    }
+
+    /// Add a GPG key to the current user
+    pub async fn create_gpg_key(&self, opt: &CreateGPGKeyOption) -> Result<GPGKey> {
+        let resp = self
+            .request(Method::POST, "user/gpg_keys")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create gpg key failed").await
+    }
+
+    /// Delete a GPG key of the current user
+    pub async fn delete_gpg_key(&self, id: i64) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("user/gpg_keys/{}", id))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete gpg key failed").await
+    }
+
+    /// Get the token to sign for verifying a GPG key
+    pub async fn get_gpg_key_token(&self) -> Result<String> {
+        let resp = self
+            .request(Method::GET, "user/gpg_key_token")?
+            .send()
+            .await?;
+
+        resp_text(resp, "get gpg key token failed").await
+    }
+
+    /// Verify a GPG key with the signed token
+    pub async fn verify_gpg_key(&self, opt: &VerifyGPGKeyOption) -> Result<GPGKey> {
+        let resp = self
+            .request(Method::POST, "user/gpg_key_verify")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "verify gpg key failed").await
+    }
+
+    /// List the deploy keys of a repo
+    pub async fn list_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: Pagination,
+    ) -> Result<Vec<DeployKey>> {
+        let resp = self
+            .request(Method::GET, &format!("repos/{}/{}/keys", owner, repo))?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(
+            resp,
+            &format!("list deploy keys of repo {}/{} failed", owner, repo),
+        )
+        .await
+    }
+
+    /// Get a deploy key of a repo
+    pub async fn get_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<DeployKey> {
+        let resp = self
+            .request(
+                Method::GET,
+                &format!("repos/{}/{}/keys/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        resp_json(resp, "get deploy key failed").await
+    }
+
+    /// Add a deploy key to a repo, set `opt.read_only` to false to allow
+    /// pushing with it
+    pub async fn create_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        opt: &CreateKeyOption,
+    ) -> Result<DeployKey> {
+        let resp = self
+            .request(Method::POST, &format!("repos/{}/{}/keys", owner, repo))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create deploy key failed").await
+    }
+
+    /// Delete a deploy key of a repo
+    pub async fn delete_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        id: i64,
+    ) -> Result<()> {
+        let resp = self
+            .request(
+                Method::DELETE,
+                &format!("repos/{}/{}/keys/{}", owner, repo, id),
+            )?
+            .send()
+            .await?;
+
+        check_success(resp, "delete deploy key failed").await
+    }
+
+    // ===============================================
+    // Application related apis
+    // ===============================================
+
+    /// List the access tokens of the basic auth user, gitea only allows this
+    /// with basic auth
+    pub async fn list_access_tokens(
+        &self,
+        page: Pagination,
+    ) -> Result<Vec<PersonalAccessToken>> {
+        let resp = self
+            .access_tokens_request(Method::GET, None)?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list access tokens failed").await
+    }
+
+    /// Create an access token for the basic auth user, gitea only allows this
+    /// with basic auth
+    pub async fn create_access_token(
+        &self,
+        opt: &CreateAccessTokenOption,
+    ) -> Result<PersonalAccessToken> {
+        let resp = self
+            .access_tokens_request(Method::POST, None)?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create access token failed").await
+    }
+
+    /// Delete an access token of the basic auth user by its id or name, gitea
+    /// only allows this with basic auth
+    pub async fn delete_access_token(&self, token: &str) -> Result<()> {
+        let resp = self
+            .access_tokens_request(Method::DELETE, Some(token))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete access token failed").await
+    }
+
+    /// List the OAuth2 applications of the current user
+    pub async fn list_oauth2_applications(
+        &self,
+        page: Pagination,
+    ) -> Result<Vec<OAuth2Application>> {
+        let resp = self
+            .request(Method::GET, "user/applications/oauth2")?
+            .query(&page.to_query())
+            .send()
+            .await?;
+
+        resp_json(resp, "list oauth2 applications failed").await
+    }
+
+    /// Get an OAuth2 application of the current user
+    pub async fn get_oauth2_application(&self, id: i64) -> Result<OAuth2Application> {
+        let resp = self
+            .request(Method::GET, &format!("user/applications/oauth2/{}", id))?
+            .send()
+            .await?;
+
+        resp_json(resp, "get oauth2 application failed").await
+    }
+
+    /// Create an OAuth2 application
+    pub async fn create_oauth2_application(
+        &self,
+        opt: &CreateOAuth2ApplicationOptions,
+    ) -> Result<OAuth2Application> {
+        let resp = self
+            .request(Method::POST, "user/applications/oauth2")?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "create oauth2 application failed").await
+    }
+
+    /// Update an OAuth2 application, this regenerates its client secret
+    pub async fn update_oauth2_application(
+        &self,
+        id: i64,
+        opt: &CreateOAuth2ApplicationOptions,
+    ) -> Result<OAuth2Application> {
+        let resp = self
+            .request(Method::PATCH, &format!("user/applications/oauth2/{}", id))?
+            .json(opt)
+            .send()
+            .await?;
+
+        resp_json(resp, "update oauth2 application failed").await
+    }
+
+    /// Delete an OAuth2 application
+    pub async fn delete_oauth2_application(&self, id: i64) -> Result<()> {
+        let resp = self
+            .request(Method::DELETE, &format!("user/applications/oauth2/{}", id))?
+            .send()
+            .await?;
+
+        check_success(resp, "delete oauth2 application failed").await
+    }
+}
+
+/// Percent-encode `segment` to be used as a single segment of an url path,
+/// e.g. a branch protection rule named `release/*`
+fn encode_segment(segment: &str) -> String {
+    let mut url = Url::parse("http://localhost/").expect("valid url");
+    url.path_segments_mut()
+        .expect("url with a path")
+        .pop_if_empty()
+        .push(segment);
+
+    url.path()[1..].to_string()
+}
+
+/// Percent-encode every `/`-separated segment of `path`, e.g. the path of a
+/// file named `c#.md`
+fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(encode_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn ref_query(ref_: Option<&str>) -> Vec<(String, String)> {
+    match ref_ {
+        Some(ref_) => vec![("ref".to_string(), ref_.to_string())],
+        None => vec![],
+    }
+}
+
+pub async fn resp_json<T>(resp: reqwest::Response, err_mes: &str) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    if !resp.status().is_success() {
+        Err(Error::GiteaError(format!(
+            "{}: [{}] {}",
+            err_mes,
+            resp.status(),
+            resp.text().await?
+        )))
+    } else {
+        Ok(resp.json::<T>().await?)
+    }
+}
+
+pub async fn resp_text(resp: reqwest::Response, err_mes: &str) -> Result<String> {
+    if !resp.status().is_success() {
+        Err(Error::GiteaError(format!(
+            "{}: [{}] {}",
+            err_mes,
+            resp.status(),
+            resp.text().await?
+        )))
+    } else {
+        Ok(resp.text().await?)
+    }
+}
+
+/// Like `resp_json`, but hands the body out as a stream of chunks instead of
+/// buffering it in memory
+pub async fn resp_stream(
+    resp: reqwest::Response,
+    err_mes: &str,
+) -> Result<impl Stream<Item = Result<Bytes>>> {
+    if !resp.status().is_success() {
+        Err(Error::GiteaError(format!(
+            "{}: [{}] {}",
+            err_mes,
+            resp.status(),
+            resp.text().await?
+        )))
+    } else {
+        Ok(resp.bytes_stream().map_err(Error::from))
+    }
+}
+
+pub async fn check_success(resp: reqwest::Response, err_mes: &str) -> Result<()> {
+    if !resp.status().is_success() {
+        Err(Error::GiteaError(format!(
+            "{}: [{}] {}",
             err_mes,
             resp.status(),
             resp.text().await?
@@ -224,3 +3502,116 @@ pub async fn check_success(resp: reqwest::Response, err_mes: &str) -> Result<()>
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header<'a>(req: &'a reqwest::Request, key: &str) -> Option<&'a str> {
+        req.headers().get(key).map(|value| value.to_str().unwrap())
+    }
+
+    #[test]
+    fn request_sends_sudo_and_otp() {
+        let cli = Gritea::builder("gitea.example.com")
+            .token("secret")
+            .sudo("foo")
+            .otp("123456")
+            .build()
+            .unwrap();
+
+        let req = cli.request(Method::GET, "user").unwrap().build().unwrap();
+        assert_eq!(header(&req, "Authorization"), Some("token secret"));
+        assert_eq!(header(&req, "Sudo"), Some("foo"));
+        assert_eq!(header(&req, "X-Gitea-OTP"), Some("123456"));
+
+        let req = cli
+            .with_basic_auth("foo", "bar")
+            .request(Method::GET, "user")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(header(&req, "Authorization"), Some("Basic Zm9vOmJhcg=="));
+        assert_eq!(header(&req, "Sudo"), Some("foo"));
+        assert_eq!(header(&req, "X-Gitea-OTP"), Some("123456"));
+    }
+
+    #[test]
+    fn access_tokens_request_uses_basic_auth_without_sudo() {
+        let cli = Gritea::builder("gitea.example.com")
+            .token("secret")
+            .sudo("bar")
+            .otp("123456")
+            .build()
+            .unwrap();
+        assert!(cli.access_tokens_request(Method::GET, None).is_err());
+
+        let req = cli
+            .with_basic_auth("foo", "bar")
+            .access_tokens_request(Method::DELETE, Some("ci"))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            req.url().as_str(),
+            "https://gitea.example.com/api/v1/users/foo/tokens/ci"
+        );
+        assert_eq!(header(&req, "Authorization"), Some("Basic Zm9vOmJhcg=="));
+        assert_eq!(header(&req, "Sudo"), None);
+        assert_eq!(header(&req, "X-Gitea-OTP"), Some("123456"));
+    }
+
+    #[test]
+    fn with_sudo_and_otp_override() {
+        let cli = Gritea::builder("gitea.example.com")
+            .token("secret")
+            .sudo("foo")
+            .build()
+            .unwrap();
+
+        let req = cli
+            .with_sudo(None)
+            .with_otp(Some("123456"))
+            .request(Method::GET, "user")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(header(&req, "Sudo"), None);
+        assert_eq!(header(&req, "X-Gitea-OTP"), Some("123456"));
+
+        // the original client is left untouched
+        let req = cli.request(Method::GET, "user").unwrap().build().unwrap();
+        assert_eq!(header(&req, "Sudo"), Some("foo"));
+        assert_eq!(header(&req, "X-Gitea-OTP"), None);
+    }
+
+    #[test]
+    fn request_encodes_path_segment() {
+        let cli = Gritea::builder("gitea.example.com")
+            .token("secret")
+            .build()
+            .unwrap();
+
+        let req = cli
+            .request(
+                Method::GET,
+                &format!(
+                    "repos/foo/bar/branch_protections/{}",
+                    encode_segment("release/*")
+                ),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            req.url().as_str(),
+            "https://gitea.example.com/api/v1/repos/foo/bar/branch_protections/release%2F*"
+        );
+    }
+
+    #[test]
+    fn encode_path_keeps_separators() {
+        assert_eq!(encode_path("docs/c#.md"), "docs/c%23.md");
+        assert_eq!(encode_path("a b/what?.md"), "a%20b/what%3F.md");
+    }
+}
@@ -0,0 +1,294 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    hook::PayloadCommitVerification,
+    repo::{CommitMeta, CommitUser, Identity},
+};
+
+/// Kind of an entry returned by the contents api
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentType {
+    File,
+    Dir,
+    Symlink,
+    Submodule,
+}
+
+/// FileLinksResponse contains the links for a repo's file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FileLinksResponse {
+    #[serde(rename = "self")]
+    pub self_: Option<String>,
+    pub git: Option<String>,
+    pub html: Option<String>,
+}
+
+/// ContentsResponse contains information about a repo's entry's (dir, file,
+/// symlink, submodule) metadata and content
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ContentsResponse {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    #[serde(rename = "type")]
+    pub type_: ContentType,
+    pub size: i64,
+    /// `Some("base64")` if `content` is set
+    pub encoding: Option<String>,
+    /// Only set when `type_` is `File`, use `decode_content` to get the raw
+    /// bytes
+    pub content: Option<String>,
+    /// Only set when `type_` is `Symlink`
+    pub target: Option<String>,
+    pub url: Option<String>,
+    pub html_url: Option<String>,
+    pub git_url: Option<String>,
+    pub download_url: Option<String>,
+    /// Only set when `type_` is `Submodule`
+    pub submodule_git_url: Option<String>,
+    #[serde(rename = "_links")]
+    pub links: FileLinksResponse,
+}
+
+impl ContentsResponse {
+    /// Decode the base64 encoded content of a file
+    pub fn decode_content(&self) -> Result<Vec<u8>> {
+        match &self.content {
+            Some(content) => decode_base64(content),
+            None => Err(Error::Other(format!("{} has no content", self.path))),
+        }
+    }
+}
+
+pub(crate) fn decode_base64(content: &str) -> Result<Vec<u8>> {
+    // gitea may wrap the encoded content into multiple lines
+    Ok(base64::decode(
+        content
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>(),
+    )?)
+}
+
+/// CommitDateOptions store dates for GIT_AUTHOR_DATE and GIT_COMMITTER_DATE
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CommitDateOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<DateTime<Utc>>,
+}
+
+/// FileOptions options for all file APIs
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct FileOptions {
+    /// Commit message, gitea generates one if empty
+    pub message: String,
+    /// Branch to base the commit on, defaults to the default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    /// Create a new branch from `branch` for the commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<Identity>,
+    pub dates: CommitDateOptions,
+    pub signoff: bool,
+}
+
+/// Options when create a file
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateFileOptions {
+    #[serde(flatten)]
+    pub file: FileOptions,
+    /// Base64 encoded content
+    pub content: String,
+}
+
+impl CreateFileOptions {
+    pub fn new(content: impl AsRef<[u8]>) -> Self {
+        Self {
+            file: FileOptions::default(),
+            content: base64::encode(content),
+        }
+    }
+}
+
+/// Options when update a file
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct UpdateFileOptions {
+    #[serde(flatten)]
+    pub file: FileOptions,
+    /// Sha of the file being replaced
+    pub sha: String,
+    /// Base64 encoded content
+    pub content: String,
+    /// Move the file from this path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_path: Option<String>,
+}
+
+impl UpdateFileOptions {
+    pub fn new(sha: impl Into<String>, content: impl AsRef<[u8]>) -> Self {
+        Self {
+            file: FileOptions::default(),
+            sha: sha.into(),
+            content: base64::encode(content),
+            from_path: None,
+        }
+    }
+}
+
+/// Options when delete a file
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DeleteFileOptions {
+    #[serde(flatten)]
+    pub file: FileOptions,
+    /// Sha of the file being deleted
+    pub sha: String,
+}
+
+impl DeleteFileOptions {
+    pub fn new(sha: impl Into<String>) -> Self {
+        Self {
+            file: FileOptions::default(),
+            sha: sha.into(),
+        }
+    }
+}
+
+/// Kind of a single operation of `ChangeFilesOptions`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+/// ChangeFileOperation for creating, updating or deleting a file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChangeFileOperation {
+    pub operation: FileOperation,
+    pub path: String,
+    /// Base64 encoded content, required for `Create` and `Update`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Sha of the file, required for `Update` and `Delete`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_path: Option<String>,
+}
+
+impl ChangeFileOperation {
+    pub fn create(path: impl Into<String>, content: impl AsRef<[u8]>) -> Self {
+        Self {
+            operation: FileOperation::Create,
+            path: path.into(),
+            content: Some(base64::encode(content)),
+            sha: None,
+            from_path: None,
+        }
+    }
+
+    pub fn update(
+        path: impl Into<String>,
+        sha: impl Into<String>,
+        content: impl AsRef<[u8]>,
+    ) -> Self {
+        Self {
+            operation: FileOperation::Update,
+            path: path.into(),
+            content: Some(base64::encode(content)),
+            sha: Some(sha.into()),
+            from_path: None,
+        }
+    }
+
+    pub fn delete(path: impl Into<String>, sha: impl Into<String>) -> Self {
+        Self {
+            operation: FileOperation::Delete,
+            path: path.into(),
+            content: None,
+            sha: Some(sha.into()),
+            from_path: None,
+        }
+    }
+}
+
+/// Options when change multiple files in one commit
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ChangeFilesOptions {
+    #[serde(flatten)]
+    pub file: FileOptions,
+    pub files: Vec<ChangeFileOperation>,
+}
+
+/// FileCommitResponse contains information generated from a Git commit for
+/// a repo's file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FileCommitResponse {
+    pub url: String,
+    pub sha: String,
+    pub html_url: String,
+    pub author: CommitUser,
+    pub committer: CommitUser,
+    pub parents: Vec<CommitMeta>,
+    pub message: String,
+    pub tree: CommitMeta,
+}
+
+/// FileResponse contains information about a repo's file
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FileResponse {
+    pub content: Option<ContentsResponse>,
+    pub commit: FileCommitResponse,
+    pub verification: Option<PayloadCommitVerification>,
+}
+
+/// FilesResponse contains information about multiple files from a repo
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FilesResponse {
+    /// None for deleted files
+    pub files: Vec<Option<ContentsResponse>>,
+    pub commit: FileCommitResponse,
+    pub verification: Option<PayloadCommitVerification>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_file_options_serialize() {
+        let mut opt = CreateFileOptions::new("foo: bar\n");
+        opt.file.message = "bump".to_string();
+
+        let value = serde_json::to_value(&opt).unwrap();
+        assert_eq!(value["message"], "bump");
+        assert_eq!(value["content"], "Zm9vOiBiYXIK");
+        assert!(value.get("branch").is_none());
+    }
+
+    #[test]
+    fn contents_response_decode_content() {
+        let resp: ContentsResponse = serde_json::from_str(
+            r#"{
+                "name": "a.yml", "path": "a.yml", "sha": "abc", "type": "file",
+                "size": 9, "encoding": "base64", "content": "Zm9vOiBi\nYXIK",
+                "target": null, "url": null, "html_url": null, "git_url": null,
+                "download_url": null, "submodule_git_url": null,
+                "_links": {"self": null, "git": null, "html": null}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.decode_content().unwrap(), b"foo: bar\n");
+    }
+}
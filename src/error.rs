@@ -27,6 +27,9 @@ pub enum Error {
     #[error("{0}")]
     SerdeJsonError(#[from] serde_json::Error),
 
+    #[error("failed to decode base64: {0}")]
+    Base64Error(#[from] base64::DecodeError),
+
     #[error("{0}")]
     Other(String),
 }
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{contents::decode_base64, error::Result};
+
+/// GitObject represents a Git object
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitObject {
+    /// One of `commit`, `tree`, `blob` or `tag`
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub sha: String,
+    pub url: String,
+}
+
+/// Reference represents a Git reference
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Reference {
+    /// Full name of the ref, e.g. `refs/heads/main`
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub url: String,
+    pub object: GitObject,
+}
+
+/// Gitea returns a single reference instead of a list when the filter of
+/// `list_refs` names exactly one ref
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum References {
+    Many(Vec<Reference>),
+    One(Reference),
+}
+
+impl From<References> for Vec<Reference> {
+    fn from(refs: References) -> Self {
+        match refs {
+            References::Many(refs) => refs,
+            References::One(ref_) => vec![ref_],
+        }
+    }
+}
+
+/// GitEntry represents a git tree
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitEntry {
+    pub path: String,
+    pub mode: String,
+    /// One of `blob`, `tree` or `commit` (for submodules)
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub size: i64,
+    pub sha: String,
+    pub url: String,
+}
+
+/// GitTreeResponse returns a git tree
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitTreeResponse {
+    pub sha: String,
+    pub url: String,
+    pub tree: Vec<GitEntry>,
+    /// More entries are available on the next page
+    pub truncated: bool,
+    pub page: i64,
+    pub total_count: i64,
+}
+
+/// GitBlobResponse represents a git blob
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GitBlobResponse {
+    /// Base64 encoded content, use `decode_content` to get the raw bytes
+    pub content: String,
+    pub encoding: String,
+    pub url: String,
+    pub sha: String,
+    pub size: i64,
+}
+
+impl GitBlobResponse {
+    /// Decode the base64 encoded content of the blob
+    pub fn decode_content(&self) -> Result<Vec<u8>> {
+        decode_base64(&self.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_deserialize_one_or_many() {
+        let ref_ = r#"{
+            "ref": "refs/heads/main", "url": "",
+            "object": {"type": "commit", "sha": "abc", "url": ""}
+        }"#;
+
+        let one: Vec<Reference> =
+            serde_json::from_str::<References>(ref_).unwrap().into();
+        let many: Vec<Reference> =
+            serde_json::from_str::<References>(&format!("[{}, {}]", ref_, ref_))
+                .unwrap()
+                .into();
+
+        assert_eq!(one.len(), 1);
+        assert_eq!(one[0].ref_, "refs/heads/main");
+        assert_eq!(many.len(), 2);
+    }
+}
@@ -55,7 +55,7 @@ pub struct PayloadCommitVerification {
     pub verified: bool,
     pub reason: String,
     pub signature: String,
-    pub signer: PayloadUser,
+    pub signer: Option<PayloadUser>,
     pub payload: String,
 }
 
@@ -0,0 +1,378 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::user::User;
+
+/// State of an issue or a pull request
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueState {
+    Open,
+    Closed,
+    /// Only meaningful as a filter when listing
+    All,
+}
+
+impl std::fmt::Display for IssueState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                IssueState::Open => "open",
+                IssueState::Closed => "closed",
+                IssueState::All => "all",
+            }
+        )
+    }
+}
+
+/// Whether to list issues, pull requests or both
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueType {
+    Issues,
+    Pulls,
+}
+
+impl std::fmt::Display for IssueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                IssueType::Issues => "issues",
+                IssueType::Pulls => "pulls",
+            }
+        )
+    }
+}
+
+/// Label a label to an issue or a pr
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Label {
+    pub id: i64,
+    pub name: String,
+    pub color: String,
+    pub description: String,
+    pub url: String,
+}
+
+/// Options when create a label
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateLabelOption {
+    pub name: String,
+    /// Hex color such as `#e11d21`
+    pub color: String,
+    pub description: String,
+}
+
+/// Options when edit a label, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditLabelOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Label ids to add to or replace on an issue
+#[derive(Debug, Deserialize, Serialize)]
+pub struct IssueLabelsOption {
+    pub labels: Vec<i64>,
+}
+
+/// Milestone is a collection of issues on one repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Milestone {
+    pub id: i64,
+    pub title: String,
+    pub description: String,
+    pub state: IssueState,
+    pub open_issues: i64,
+    pub closed_issues: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+/// Filters when list milestones of a repository, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListMilestoneOption {
+    pub state: Option<IssueState>,
+    pub name: Option<String>,
+}
+
+impl ListMilestoneOption {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(state) = &self.state {
+            query.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(name) = &self.name {
+            query.push(("name".to_string(), name.clone()));
+        }
+
+        query
+    }
+}
+
+/// Options when create a milestone
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateMilestoneOption {
+    pub title: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueState>,
+}
+
+/// Options when edit a milestone, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditMilestoneOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+/// PullRequestMeta is a short summary of the pull request an issue belongs to
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PullRequestMeta {
+    pub merged: bool,
+    pub merged_at: Option<DateTime<Utc>>,
+}
+
+/// RepositoryMeta basic repository information
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RepositoryMeta {
+    pub id: i64,
+    pub name: String,
+    pub owner: String,
+    pub full_name: String,
+}
+
+/// Issue represents an issue in a repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Issue {
+    pub id: i64,
+    pub url: String,
+    pub html_url: String,
+    pub number: i64,
+    pub user: User,
+    pub original_author: String,
+    pub original_author_id: i64,
+    pub title: String,
+    pub body: String,
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
+    pub assignees: Option<Vec<User>>,
+    pub state: IssueState,
+    pub is_locked: bool,
+    pub comments: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub pull_request: Option<PullRequestMeta>,
+    pub repository: Option<RepositoryMeta>,
+}
+
+/// Filters when list issues of a repository, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListIssueOption {
+    pub state: Option<IssueState>,
+    /// Label names
+    pub labels: Vec<String>,
+    /// Search string
+    pub q: Option<String>,
+    pub type_: Option<IssueType>,
+    /// Milestone names or ids
+    pub milestones: Vec<String>,
+    /// Only show items updated after the given time
+    pub since: Option<DateTime<Utc>>,
+    /// Only show items updated before the given time
+    pub before: Option<DateTime<Utc>>,
+    pub created_by: Option<String>,
+    pub assigned_by: Option<String>,
+    pub mentioned_by: Option<String>,
+}
+
+impl ListIssueOption {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(state) = &self.state {
+            query.push(("state".to_string(), state.to_string()));
+        }
+        if !self.labels.is_empty() {
+            query.push(("labels".to_string(), self.labels.join(",")));
+        }
+        if let Some(q) = &self.q {
+            query.push(("q".to_string(), q.clone()));
+        }
+        if let Some(type_) = &self.type_ {
+            query.push(("type".to_string(), type_.to_string()));
+        }
+        if !self.milestones.is_empty() {
+            query.push(("milestones".to_string(), self.milestones.join(",")));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(before) = &self.before {
+            query.push(("before".to_string(), before.to_rfc3339()));
+        }
+        if let Some(created_by) = &self.created_by {
+            query.push(("created_by".to_string(), created_by.clone()));
+        }
+        if let Some(assigned_by) = &self.assigned_by {
+            query.push(("assigned_by".to_string(), assigned_by.clone()));
+        }
+        if let Some(mentioned_by) = &self.mentioned_by {
+            query.push(("mentioned_by".to_string(), mentioned_by.clone()));
+        }
+
+        query
+    }
+}
+
+/// Options when create an issue
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateIssueOption {
+    pub title: String,
+    pub body: String,
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    /// Usernames of the assignees
+    pub assignees: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+    /// Milestone id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<i64>,
+    /// Label ids
+    pub labels: Vec<i64>,
+    pub closed: bool,
+}
+
+/// Options when edit an issue, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditIssueOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<i64>,
+    /// Set to `Closed` to close the issue or `Open` to reopen it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unset_due_date: Option<bool>,
+}
+
+/// Comment represents a comment on a commit or issue
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Comment {
+    pub id: i64,
+    pub html_url: String,
+    pub pull_request_url: String,
+    pub issue_url: String,
+    pub user: User,
+    pub original_author: String,
+    pub original_author_id: i64,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Filters when list comments, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListIssueCommentOption {
+    /// Only show comments updated after the given time
+    pub since: Option<DateTime<Utc>>,
+    /// Only show comments updated before the given time
+    pub before: Option<DateTime<Utc>>,
+}
+
+impl ListIssueCommentOption {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(since) = &self.since {
+            query.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(before) = &self.before {
+            query.push(("before".to_string(), before.to_rfc3339()));
+        }
+
+        query
+    }
+}
+
+/// Options when create a comment
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateIssueCommentOption {
+    pub body: String,
+}
+
+/// Options when edit a comment
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EditIssueCommentOption {
+    pub body: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_issue_option_to_query() {
+        let opt = ListIssueOption {
+            state: Some(IssueState::Closed),
+            labels: vec!["bug".to_string(), "ci".to_string()],
+            type_: Some(IssueType::Pulls),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            opt.to_query(),
+            vec![
+                ("state".to_string(), "closed".to_string()),
+                ("labels".to_string(), "bug,ci".to_string()),
+                ("type".to_string(), "pulls".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn edit_issue_option_skip_unset() {
+        let opt = EditIssueOption {
+            state: Some(IssueState::Closed),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_string(&opt).unwrap(),
+            r#"{"state":"closed"}"#
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{repo::Repository, user::User};
+
+/// PublicKey publickey is a user key to push code to repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublicKey {
+    pub id: i64,
+    pub key: String,
+    pub url: String,
+    pub title: String,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+    pub read_only: bool,
+    pub key_type: String,
+    pub user: Option<User>,
+}
+
+/// GPGKeyEmail an email attached to a GPGKey
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GPGKeyEmail {
+    pub email: String,
+    pub verified: bool,
+}
+
+/// GPGKey a user GPG key to sign commit and tag in repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GPGKey {
+    pub id: i64,
+    pub primary_key_id: String,
+    pub key_id: String,
+    pub public_key: String,
+    pub emails: Option<Vec<GPGKeyEmail>>,
+    #[serde(rename = "subsKey")]
+    pub subs_key: Option<Vec<GPGKey>>,
+    pub can_sign: bool,
+    pub can_encrypt_comms: bool,
+    pub can_encrypt_storage: bool,
+    pub can_certify: bool,
+    pub verified: bool,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// DeployKey a deploy key
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DeployKey {
+    pub id: i64,
+    pub key_id: i64,
+    pub key: String,
+    pub url: String,
+    pub title: String,
+    pub fingerprint: String,
+    pub created_at: DateTime<Utc>,
+    pub read_only: bool,
+    pub repository: Option<Repository>,
+}
+
+/// Options when create a ssh key or a deploy key
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateKeyOption {
+    pub title: String,
+    /// An armored ssh key, e.g. the content of `id_ed25519.pub`
+    pub key: String,
+    /// Only meaningful for deploy keys, which can push if false
+    pub read_only: bool,
+}
+
+impl CreateKeyOption {
+    /// A key which can only pull when used as a deploy key
+    pub fn read_only(title: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            key: key.into(),
+            read_only: true,
+        }
+    }
+
+    /// A key which can also push when used as a deploy key
+    pub fn read_write(title: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            key: key.into(),
+            read_only: false,
+        }
+    }
+}
+
+/// Options when create a GPG key
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateGPGKeyOption {
+    pub armored_public_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub armored_signature: Option<String>,
+}
+
+/// Options when verify a GPG key
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct VerifyGPGKeyOption {
+    pub key_id: String,
+    /// The token from `get_gpg_key_token` signed by the key
+    pub armored_signature: String,
+}
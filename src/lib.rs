@@ -2,10 +2,17 @@ pub mod auth;
 pub mod builder;
 pub mod client;
 pub mod config;
+pub mod contents;
 pub mod error;
+pub mod git;
 pub mod hook;
+pub mod issue;
+pub mod key;
 pub mod oauth;
+pub mod org;
 pub mod pagination;
+pub mod pull;
+pub mod release;
 pub mod repo;
 pub mod user;
 
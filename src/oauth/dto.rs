@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -29,6 +30,27 @@ pub struct AccessToken {
     // pub id_token: String,
 }
 
+/// OAuth2Application represents an OAuth2 application
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OAuth2Application {
+    pub id: i64,
+    pub name: String,
+    pub client_id: String,
+    /// Only returned on creation and update
+    pub client_secret: String,
+    pub redirect_uris: Vec<String>,
+    pub confidential_client: bool,
+    pub created: DateTime<Utc>,
+}
+
+/// Options when create or update an OAuth2 application
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateOAuth2ApplicationOptions {
+    pub name: String,
+    pub redirect_uris: Vec<String>,
+    pub confidential_client: bool,
+}
+
 impl std::fmt::Display for TokenType {
     // TODO: user serde::Serialize
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -36,8 +58,8 @@ impl std::fmt::Display for TokenType {
             f,
             "{}",
             match self {
-                &TokenType::Bearer => "bearer",
-                &TokenType::Mac => "mac",
+                TokenType::Bearer => "bearer",
+                TokenType::Mac => "mac",
             }
         )
     }
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::repo::{AccessMode, CollaboratorPermission};
+
+/// Visibility of an organization
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibleType {
+    Public,
+    /// Visible to signed-in users only
+    Limited,
+    Private,
+}
+
+/// Organization represents an organization
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Organization {
+    pub id: i64,
+    pub username: String,
+    pub full_name: String,
+    pub avatar_url: String,
+    pub description: String,
+    pub website: String,
+    pub location: String,
+    pub visibility: VisibleType,
+    pub repo_admin_change_team_access: bool,
+}
+
+/// Options when create an organization
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateOrgOption {
+    pub username: String,
+    pub full_name: String,
+    pub description: String,
+    pub website: String,
+    pub location: String,
+    /// Defaults to `Public`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<VisibleType>,
+    pub repo_admin_change_team_access: bool,
+}
+
+/// Options when edit an organization, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditOrgOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<VisibleType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_admin_change_team_access: Option<bool>,
+}
+
+/// Team represents a team in an organization
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Team {
+    pub id: i64,
+    pub name: String,
+    pub description: String,
+    pub organization: Option<Organization>,
+    pub includes_all_repositories: bool,
+    pub permission: AccessMode,
+    /// Units the team has access to, e.g. `repo.code` or `repo.issues`
+    pub units: Vec<String>,
+    /// Access level of the team per unit
+    pub units_map: HashMap<String, AccessMode>,
+    pub can_create_org_repo: bool,
+}
+
+/// Options when create a team
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateTeamOption {
+    pub name: String,
+    pub description: String,
+    pub includes_all_repositories: bool,
+    pub permission: CollaboratorPermission,
+    pub units: Vec<String>,
+    pub units_map: HashMap<String, AccessMode>,
+    pub can_create_org_repo: bool,
+}
+
+/// Options when edit a team, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditTeamOption {
+    /// Required by gitea even if unchanged
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes_all_repositories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<CollaboratorPermission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units_map: Option<HashMap<String, AccessMode>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_create_org_repo: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TeamSearchResult {
+    pub data: Vec<Team>,
+    pub ok: bool,
+}
@@ -11,6 +11,14 @@ impl Pagination {
         Self { page, limit }
     }
 
+    pub fn page(&self) -> i64 {
+        self.page
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+    }
+
     pub fn to_query(&self) -> [(String, String); 2] {
         [
             ("page".to_string(), self.page.to_string()),
@@ -0,0 +1,278 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    issue::{IssueState, Label, Milestone},
+    repo::{MergeStyle, Repository},
+    user::User,
+};
+
+/// Sort order when list pull requests
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PullRequestSort {
+    Oldest,
+    RecentUpdate,
+    LeastUpdate,
+    MostComment,
+    LeastComment,
+    Priority,
+}
+
+impl std::fmt::Display for PullRequestSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PullRequestSort::Oldest => "oldest",
+                PullRequestSort::RecentUpdate => "recentupdate",
+                PullRequestSort::LeastUpdate => "leastupdate",
+                PullRequestSort::MostComment => "mostcomment",
+                PullRequestSort::LeastComment => "leastcomment",
+                PullRequestSort::Priority => "priority",
+            }
+        )
+    }
+}
+
+/// PRBranchInfo information about a branch
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PRBranchInfo {
+    pub label: String,
+    #[serde(rename = "ref")]
+    pub ref_: String,
+    pub sha: String,
+    pub repo_id: i64,
+    /// None if the head repo has been deleted
+    pub repo: Option<Repository>,
+}
+
+/// PullRequest represents a pull request
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PullRequest {
+    pub id: i64,
+    pub url: String,
+    pub number: i64,
+    pub user: User,
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<Label>,
+    pub milestone: Option<Milestone>,
+    pub assignees: Option<Vec<User>>,
+    pub state: IssueState,
+    pub is_locked: bool,
+    pub comments: i64,
+    pub html_url: String,
+    pub diff_url: String,
+    pub patch_url: String,
+    pub mergeable: bool,
+    pub merged: bool,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub merge_commit_sha: Option<String>,
+    pub merged_by: Option<User>,
+    pub base: PRBranchInfo,
+    pub head: PRBranchInfo,
+    pub merge_base: String,
+    pub due_date: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// Filters when list pull requests of a repository, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListPullRequestsOption {
+    pub state: Option<IssueState>,
+    pub sort: Option<PullRequestSort>,
+    /// Milestone id
+    pub milestone: Option<i64>,
+    /// Label ids
+    pub labels: Vec<i64>,
+}
+
+impl ListPullRequestsOption {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(state) = &self.state {
+            query.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            query.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(milestone) = &self.milestone {
+            query.push(("milestone".to_string(), milestone.to_string()));
+        }
+        for label in &self.labels {
+            query.push(("labels".to_string(), label.to_string()));
+        }
+
+        query
+    }
+}
+
+/// Options when create a pull request
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreatePullRequestOption {
+    /// Branch name, or `owner:branch` when the head is in a fork
+    pub head: String,
+    pub base: String,
+    pub title: String,
+    pub body: String,
+    /// Usernames of the assignees
+    pub assignees: Vec<String>,
+    /// Milestone id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<i64>,
+    /// Label ids
+    pub labels: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+}
+
+/// Options when edit a pull request, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditPullRequestOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unset_due_date: Option<bool>,
+}
+
+/// Options when merge a pull request
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MergePullRequestOption {
+    #[serde(rename = "Do")]
+    pub style: MergeStyle,
+    #[serde(rename = "MergeTitleField", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "MergeMessageField", skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_after_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_merge: Option<bool>,
+    /// Fail if the head of the pr is no longer this commit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_commit_id: Option<String>,
+}
+
+impl MergePullRequestOption {
+    pub fn new(style: MergeStyle) -> Self {
+        Self {
+            style,
+            title: None,
+            message: None,
+            delete_branch_after_merge: None,
+            force_merge: None,
+            head_commit_id: None,
+        }
+    }
+}
+
+/// ReviewStateType review state type
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewStateType {
+    Approved,
+    Pending,
+    Comment,
+    RequestChanges,
+    RequestReview,
+}
+
+/// PullReview represents a pull request review
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PullReview {
+    pub id: i64,
+    pub user: Option<User>,
+    pub state: ReviewStateType,
+    pub body: String,
+    pub commit_id: String,
+    pub stale: bool,
+    pub official: bool,
+    pub dismissed: bool,
+    pub comments_count: i64,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub html_url: String,
+    pub pull_request_url: String,
+}
+
+/// PullReviewComment represents a comment on a pull request review
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PullReviewComment {
+    pub id: i64,
+    pub body: String,
+    pub user: Option<User>,
+    pub resolver: Option<User>,
+    pub pull_request_review_id: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub path: String,
+    pub commit_id: String,
+    pub original_commit_id: String,
+    pub diff_hunk: String,
+    pub position: i64,
+    pub original_position: i64,
+    pub html_url: String,
+    pub pull_request_url: String,
+}
+
+/// A line comment attached to a new pull review
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreatePullReviewComment {
+    /// The path of the file to comment on
+    pub path: String,
+    pub body: String,
+    /// Line number in the old file, 0 when commenting on the new file
+    pub old_position: i64,
+    /// Line number in the new file, 0 when commenting on the old file
+    pub new_position: i64,
+}
+
+/// Options when create a pull review
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatePullReviewOptions {
+    /// `Pending` keeps the review as a draft which can be submitted later
+    pub event: ReviewStateType,
+    pub body: String,
+    /// Defaults to the head of the pull request when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    pub comments: Vec<CreatePullReviewComment>,
+}
+
+/// Options when submit a pending pull review
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SubmitPullReviewOptions {
+    pub event: ReviewStateType,
+    pub body: String,
+}
+
+/// Options when dismiss a pull review
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DismissPullReviewOptions {
+    pub message: String,
+}
+
+/// Options when request or un-request reviewers of a pull request
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PullReviewRequestOptions {
+    /// Usernames of the reviewers
+    pub reviewers: Vec<String>,
+}
@@ -0,0 +1,4 @@
+pub mod dto;
+
+pub use crate::repo::MergeStyle;
+pub use dto::*;
@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::user::User;
+
+/// Attachment a generic attachment
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Attachment {
+    pub id: i64,
+    pub name: String,
+    pub size: i64,
+    pub download_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub uuid: String,
+    pub browser_download_url: String,
+}
+
+/// Release represents a repository release
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Release {
+    pub id: i64,
+    pub tag_name: String,
+    pub target_commitish: String,
+    pub name: String,
+    pub body: String,
+    pub url: String,
+    pub html_url: String,
+    pub tarball_url: String,
+    pub zipball_url: String,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub created_at: DateTime<Utc>,
+    pub published_at: DateTime<Utc>,
+    pub author: User,
+    pub assets: Vec<Attachment>,
+}
+
+/// Options when create a release
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateReleaseOption {
+    pub tag_name: String,
+    /// Branch or commit to create the tag at if `tag_name` doesn't exist,
+    /// defaults to the default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    pub name: String,
+    pub body: String,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+/// Options when edit a release, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditReleaseOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+}
+
+/// Options when rename an attachment
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EditAttachmentOptions {
+    pub name: String,
+}
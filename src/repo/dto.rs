@@ -1,7 +1,10 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{de::IntoDeserializer, Deserialize, Deserializer, Serialize};
 
-use crate::user::User;
+use crate::{
+    hook::{PayloadCommit, PayloadCommitVerification},
+    user::User,
+};
 
 /// Permission represents a set of permissions
 #[derive(Debug, Deserialize, Serialize)]
@@ -11,12 +14,49 @@ pub struct Permission {
     pub pull: bool,
 }
 
+/// Access level of a user on a repository, as returned by gitea
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessMode {
+    None,
+    Read,
+    Write,
+    Admin,
+    Owner,
+}
+
+/// Access level granted to a collaborator or a team
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollaboratorPermission {
+    Read,
+    Write,
+    Admin,
+}
+
+/// Options when add a collaborator
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct AddCollaboratorOption {
+    /// Defaults to `Write`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<CollaboratorPermission>,
+}
+
+/// RepoCollaboratorPermission to get repository permission for a
+/// collaborator
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RepoCollaboratorPermission {
+    pub permission: AccessMode,
+    pub role_name: String,
+    pub user: User,
+}
+
 /// InternalTracker represents settings for internal tracker
 #[derive(Debug, Deserialize, Serialize)]
 pub struct InternalTracker {
-    enable_time_tracker: bool,
-    allow_only_contributors_to_track_time: bool,
-    enable_issue_dependencies: bool,
+    pub enable_time_tracker: bool,
+    pub allow_only_contributors_to_track_time: bool,
+    pub enable_issue_dependencies: bool,
 }
 
 /// Repository represents a repository
@@ -66,6 +106,272 @@ pub struct Repository {
     pub mirror_interval: String,
 }
 
+/// Options when create a repository
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateRepoOption {
+    pub name: String,
+    pub description: String,
+    pub private: bool,
+    /// Name of the label set to use, e.g. `Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_labels: Option<String>,
+    /// Initialize the repository with the files below
+    pub auto_init: bool,
+    pub template: bool,
+    /// Comma separated names of the `.gitignore` templates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitignores: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    /// One of `default`, `collaborator`, `committer` or
+    /// `collaboratorcommitter`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trust_model: Option<String>,
+}
+
+/// MergeStyle is used specify how a pull is merged, same values as
+/// `Repository::default_merge_style`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStyle {
+    Merge,
+    Rebase,
+    RebaseMerge,
+    Squash,
+    ManuallyMerged,
+}
+
+/// Options when edit a repository, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditRepoOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_tracker: Option<InternalTracker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_pull_requests: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_whitespace_conflicts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commits: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_rebase: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_explicit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_merge_style: Option<MergeStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// Go duration string such as `8h0m0s`, only for mirrors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_interval: Option<String>,
+}
+
+/// Options when transfer a repository
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TransferRepoOption {
+    pub new_owner: String,
+    /// Teams which get access to the repository, only for organizations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_ids: Option<Vec<i64>>,
+}
+
+/// Options when generate a repository from a template
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct GenerateRepoOption {
+    /// User or organization owning the new repository
+    pub owner: String,
+    pub name: String,
+    pub description: String,
+    pub private: bool,
+    /// Include the git content of the default branch
+    pub git_content: bool,
+    pub topics: bool,
+    pub git_hooks: bool,
+    pub webhooks: bool,
+    pub avatar: bool,
+    pub labels: bool,
+}
+
+/// Options when fork a repository
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateForkOption {
+    /// Fork into this organization instead of the current user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    /// Name of the fork, defaults to the name of the original repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Service a repository is migrated from
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitServiceType {
+    /// A plain git remote
+    Git,
+    Github,
+    Gitea,
+    Gitlab,
+    Gogs,
+    Onedev,
+    Gitbucket,
+    Codebase,
+}
+
+/// Options when migrate a repository from another service
+#[derive(Deserialize, Serialize)]
+pub struct MigrateRepoOptions {
+    pub clone_addr: String,
+    /// User or organization owning the new repository
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub service: GitServiceType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_password: Option<String>,
+    /// Access token of the source service, e.g. a GitHub personal token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_token: Option<String>,
+    pub mirror: bool,
+    pub private: bool,
+    pub description: String,
+    pub wiki: bool,
+    pub milestones: bool,
+    pub labels: bool,
+    pub issues: bool,
+    pub pull_requests: bool,
+    pub releases: bool,
+    pub lfs: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lfs_endpoint: Option<String>,
+    /// Go duration string such as `8h0m0s`, only for mirrors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mirror_interval: Option<String>,
+}
+
+impl MigrateRepoOptions {
+    pub fn new(
+        clone_addr: impl Into<String>,
+        repo_owner: impl Into<String>,
+        repo_name: impl Into<String>,
+        service: GitServiceType,
+    ) -> Self {
+        Self {
+            clone_addr: clone_addr.into(),
+            repo_owner: repo_owner.into(),
+            repo_name: repo_name.into(),
+            service,
+            auth_username: None,
+            auth_password: None,
+            auth_token: None,
+            mirror: false,
+            private: false,
+            description: String::new(),
+            wiki: false,
+            milestones: false,
+            labels: false,
+            issues: false,
+            pull_requests: false,
+            releases: false,
+            lfs: false,
+            lfs_endpoint: None,
+            mirror_interval: None,
+        }
+    }
+}
+
+// keep the credentials of the source service out of logs
+impl std::fmt::Debug for MigrateRepoOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redacted = |secret: &Option<String>| {
+            secret.as_ref().map(|_| format_args!("<redacted>"))
+        };
+
+        f.debug_struct("MigrateRepoOptions")
+            .field("clone_addr", &self.clone_addr)
+            .field("repo_owner", &self.repo_owner)
+            .field("repo_name", &self.repo_name)
+            .field("service", &self.service)
+            .field("auth_username", &self.auth_username)
+            .field("auth_password", &redacted(&self.auth_password))
+            .field("auth_token", &redacted(&self.auth_token))
+            .field("mirror", &self.mirror)
+            .field("private", &self.private)
+            .field("description", &self.description)
+            .field("wiki", &self.wiki)
+            .field("milestones", &self.milestones)
+            .field("labels", &self.labels)
+            .field("issues", &self.issues)
+            .field("pull_requests", &self.pull_requests)
+            .field("releases", &self.releases)
+            .field("lfs", &self.lfs)
+            .field("lfs_endpoint", &self.lfs_endpoint)
+            .field("mirror_interval", &self.mirror_interval)
+            .finish()
+    }
+}
+
+/// PushMirror represents information of a push mirror
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PushMirror {
+    pub repo_name: String,
+    pub remote_name: String,
+    pub remote_address: String,
+    pub created: String,
+    pub last_update: Option<String>,
+    pub last_error: String,
+    pub interval: String,
+    pub sync_on_commit: bool,
+}
+
+// keep the credentials of the remote out of logs
+impl std::fmt::Debug for CreatePushMirrorOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreatePushMirrorOption")
+            .field("remote_address", &self.remote_address)
+            .field("remote_username", &self.remote_username)
+            .field("remote_password", &format_args!("<redacted>"))
+            .field("interval", &self.interval)
+            .field("sync_on_commit", &self.sync_on_commit)
+            .finish()
+    }
+}
+
+/// Options when add a push mirror
+#[derive(Default, Deserialize, Serialize)]
+pub struct CreatePushMirrorOption {
+    pub remote_address: String,
+    pub remote_username: String,
+    pub remote_password: String,
+    /// Go duration string such as `8h0m0s`
+    pub interval: String,
+    pub sync_on_commit: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CommitStatusState {
@@ -76,6 +382,22 @@ pub enum CommitStatusState {
     Warning,
 }
 
+impl std::fmt::Display for CommitStatusState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CommitStatusState::Pending => "pending",
+                CommitStatusState::Success => "success",
+                CommitStatusState::Error => "error",
+                CommitStatusState::Failure => "failure",
+                CommitStatusState::Warning => "warning",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CommitStatus {
     pub id: i64,
@@ -97,26 +419,476 @@ pub struct CreateStatusOption {
     pub context: String,
 }
 
+/// CombinedStatus holds the combined state of several statuses for a single
+/// commit
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CombinedStatus {
+    /// The worst state of the latest status of each context, None if no status
+    /// has been reported for the commit yet
+    #[serde(deserialize_with = "empty_state_as_none")]
+    pub state: Option<CommitStatusState>,
+    pub sha: String,
+    pub total_count: i64,
+    #[serde(deserialize_with = "null_as_default")]
+    pub statuses: Vec<CommitStatus>,
+    pub repository: Option<Repository>,
+    pub commit_url: String,
+    pub url: String,
+}
+
+// gitea answers with an empty `CombinedStatus` when there are no statuses
+fn empty_state_as_none<'de, D>(
+    deserializer: D,
+) -> Result<Option<CommitStatusState>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let state = Option::<String>::deserialize(deserializer)?;
+    match state.as_deref() {
+        None | Some("") => Ok(None),
+        Some(state) => Ok(Some(CommitStatusState::deserialize(
+            state.into_deserializer(),
+        )?)),
+    }
+}
+
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Sort order when list commit statuses
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommitStatusSort {
+    Oldest,
+    RecentUpdate,
+    LeastUpdate,
+    LeastIndex,
+    HighestIndex,
+}
+
+impl std::fmt::Display for CommitStatusSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CommitStatusSort::Oldest => "oldest",
+                CommitStatusSort::RecentUpdate => "recentupdate",
+                CommitStatusSort::LeastUpdate => "leastupdate",
+                CommitStatusSort::LeastIndex => "leastindex",
+                CommitStatusSort::HighestIndex => "highestindex",
+            }
+        )
+    }
+}
+
+/// Filters when list commit statuses, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListStatusesOption {
+    pub sort: Option<CommitStatusSort>,
+    pub state: Option<CommitStatusState>,
+}
+
+impl ListStatusesOption {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(sort) = &self.sort {
+            query.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &self.state {
+            query.push(("state".to_string(), state.to_string()));
+        }
+
+        query
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SearchResult {
     pub data: Vec<Repository>,
     pub ok: bool,
 }
 
+/// Identity for a person's identity like an author or committer
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+/// CommitUser contains information of a user in the context of a commit
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CommitUser {
+    pub name: String,
+    pub email: String,
+    pub date: String,
+}
+
+/// CommitMeta contains meta information of a commit in terms of API
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CommitMeta {
+    pub url: String,
+    pub sha: String,
+}
+
+/// RepoCommit contains information of a commit in the context of a repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RepoCommit {
+    pub url: String,
+    pub author: CommitUser,
+    pub committer: CommitUser,
+    pub message: String,
+    pub tree: CommitMeta,
+    pub verification: Option<PayloadCommitVerification>,
+}
+
+/// CommitAffectedFiles store information about files affected by the commit
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CommitAffectedFiles {
+    pub filename: String,
+    /// One of `added`, `removed`, `modified`, `renamed` or `copied`
+    pub status: String,
+}
+
+/// CommitStats is statistics for a RepoCommit
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CommitStats {
+    pub total: i64,
+    pub additions: i64,
+    pub deletions: i64,
+}
+
+/// Commit contains information generated from a Git commit
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Commit {
+    pub url: String,
+    pub sha: String,
+    pub created: DateTime<Utc>,
+    pub html_url: String,
+    pub commit: RepoCommit,
+    /// None if the author is not a gitea user
+    pub author: Option<User>,
+    /// None if the committer is not a gitea user
+    pub committer: Option<User>,
+    pub parents: Vec<CommitMeta>,
+    pub files: Option<Vec<CommitAffectedFiles>>,
+    pub stats: Option<CommitStats>,
+}
+
+/// Filters when list commits of a repository, unset fields are not sent
+#[derive(Debug, Default)]
+pub struct ListCommitOptions {
+    /// Sha or branch to start listing commits from
+    pub sha: Option<String>,
+    /// Only commits touching this file path
+    pub path: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    /// Include `stats`, costly on large repos
+    pub stat: Option<bool>,
+    /// Include `files`
+    pub files: Option<bool>,
+}
+
+impl ListCommitOptions {
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+
+        if let Some(sha) = &self.sha {
+            query.push(("sha".to_string(), sha.clone()));
+        }
+        if let Some(path) = &self.path {
+            query.push(("path".to_string(), path.clone()));
+        }
+        if let Some(since) = &self.since {
+            query.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(until) = &self.until {
+            query.push(("until".to_string(), until.to_rfc3339()));
+        }
+        if let Some(stat) = &self.stat {
+            query.push(("stat".to_string(), stat.to_string()));
+        }
+        if let Some(files) = &self.files {
+            query.push(("files".to_string(), files.to_string()));
+        }
+
+        query
+    }
+}
+
+/// Compare is the result of comparing two refs
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Compare {
+    pub total_commits: i64,
+    pub commits: Vec<Commit>,
+}
+
+/// Format of a repository archive
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+    Bundle,
+}
+
+impl std::fmt::Display for ArchiveFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ArchiveFormat::Zip => "zip",
+                ArchiveFormat::TarGz => "tar.gz",
+                ArchiveFormat::Bundle => "bundle",
+            }
+        )
+    }
+}
+
+/// Branch represents a repository branch
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Branch {
+    pub name: String,
+    pub commit: PayloadCommit,
+    pub protected: bool,
+    pub required_approvals: i64,
+    pub enable_status_check: bool,
+    pub status_check_contexts: Vec<String>,
+    pub user_can_push: bool,
+    pub user_can_merge: bool,
+    pub effective_branch_protection_name: String,
+}
+
+/// Options when create a branch
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateBranchRepoOption {
+    pub new_branch_name: String,
+    /// Branch to start from, defaults to the repository default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_branch_name: Option<String>,
+    /// Branch, tag or commit to start from, takes precedence over
+    /// `old_branch_name`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_ref_name: Option<String>,
+}
+
+/// Options when rename a branch
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RenameBranchRepoOption {
+    pub name: String,
+}
+
+/// BranchProtection represents a branch protection for a repository
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BranchProtection {
+    /// Deprecated by gitea in favor of `rule_name`
+    pub branch_name: String,
+    /// Branch name or glob pattern the rule applies to
+    pub rule_name: String,
+    pub enable_push: bool,
+    pub enable_push_whitelist: bool,
+    pub push_whitelist_usernames: Vec<String>,
+    pub push_whitelist_teams: Vec<String>,
+    pub push_whitelist_deploy_keys: bool,
+    pub enable_merge_whitelist: bool,
+    pub merge_whitelist_usernames: Vec<String>,
+    pub merge_whitelist_teams: Vec<String>,
+    pub enable_status_check: bool,
+    pub status_check_contexts: Vec<String>,
+    pub required_approvals: i64,
+    pub enable_approvals_whitelist: bool,
+    pub approvals_whitelist_username: Vec<String>,
+    pub approvals_whitelist_teams: Vec<String>,
+    pub block_on_rejected_reviews: bool,
+    pub block_on_official_review_requests: bool,
+    pub block_on_outdated_branch: bool,
+    pub dismiss_stale_approvals: bool,
+    pub require_signed_commits: bool,
+    /// Semicolon separated glob patterns of files which can't be changed
+    pub protected_file_patterns: String,
+    /// Semicolon separated glob patterns of files which can be pushed even
+    /// when pushing is disabled
+    pub unprotected_file_patterns: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Options when create a branch protection
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateBranchProtectionOption {
+    /// Deprecated by gitea in favor of `rule_name`
+    pub branch_name: String,
+    pub rule_name: String,
+    pub enable_push: bool,
+    pub enable_push_whitelist: bool,
+    pub push_whitelist_usernames: Vec<String>,
+    pub push_whitelist_teams: Vec<String>,
+    pub push_whitelist_deploy_keys: bool,
+    pub enable_merge_whitelist: bool,
+    pub merge_whitelist_usernames: Vec<String>,
+    pub merge_whitelist_teams: Vec<String>,
+    pub enable_status_check: bool,
+    pub status_check_contexts: Vec<String>,
+    pub required_approvals: i64,
+    pub enable_approvals_whitelist: bool,
+    pub approvals_whitelist_username: Vec<String>,
+    pub approvals_whitelist_teams: Vec<String>,
+    pub block_on_rejected_reviews: bool,
+    pub block_on_official_review_requests: bool,
+    pub block_on_outdated_branch: bool,
+    pub dismiss_stale_approvals: bool,
+    pub require_signed_commits: bool,
+    /// Semicolon separated glob patterns of files which can't be changed
+    pub protected_file_patterns: String,
+    /// Semicolon separated glob patterns of files which can be pushed even
+    /// when pushing is disabled
+    pub unprotected_file_patterns: String,
+}
+
+/// Options when edit a branch protection, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditBranchProtectionOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_push: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_push_whitelist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_whitelist_usernames: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_whitelist_teams: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push_whitelist_deploy_keys: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_merge_whitelist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_whitelist_usernames: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_whitelist_teams: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_status_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_check_contexts: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approvals: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_approvals_whitelist: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvals_whitelist_username: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approvals_whitelist_teams: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_on_rejected_reviews: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_on_official_review_requests: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_on_outdated_branch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismiss_stale_approvals: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_signed_commits: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_file_patterns: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unprotected_file_patterns: Option<String>,
+}
+
+/// Tag represents a repository tag
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Tag {
+    pub name: String,
+    /// Empty for lightweight tags
+    pub message: String,
+    /// Sha of the tag object for annotated tags, of the commit otherwise
+    pub id: String,
+    pub commit: CommitMeta,
+    pub zipball_url: String,
+    pub tarball_url: String,
+}
+
+/// AnnotatedTagObject contains meta information of the tag object
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AnnotatedTagObject {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub url: String,
+    pub sha: String,
+}
+
+/// AnnotatedTag represents an annotated tag
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AnnotatedTag {
+    pub tag: String,
+    pub sha: String,
+    pub url: String,
+    pub message: String,
+    pub tagger: CommitUser,
+    pub object: AnnotatedTagObject,
+    pub verification: Option<PayloadCommitVerification>,
+}
+
+/// Options when create a tag
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateTagOption {
+    pub tag_name: String,
+    /// Creates an annotated tag if set, a lightweight one otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Branch or commit to tag, defaults to the default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+/// TagProtection represents a tag protection
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TagProtection {
+    pub id: i64,
+    /// Tag name, glob pattern or regex wrapped in `/`
+    pub name_pattern: String,
+    pub whitelist_usernames: Vec<String>,
+    pub whitelist_teams: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Options when create a tag protection
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateTagProtectionOption {
+    pub name_pattern: String,
+    pub whitelist_usernames: Vec<String>,
+    pub whitelist_teams: Vec<String>,
+}
+
+/// Options when edit a tag protection, unset fields are left untouched
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EditTagProtectionOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitelist_usernames: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitelist_teams: Option<Vec<String>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn commit_status_state_serialize() {
-        let objs = vec![
+        let objs = [
             CommitStatusState::Pending,
             CommitStatusState::Success,
             CommitStatusState::Error,
             CommitStatusState::Failure,
             CommitStatusState::Warning,
         ];
-        let reprs = vec![
+        let reprs = [
             "\"pending\"",
             "\"success\"",
             "\"error\"",
@@ -129,4 +901,58 @@ mod tests {
             assert_eq!(&res_repr, repr);
         }
     }
+
+    #[test]
+    fn combined_status_deserialize_empty() {
+        let status: CombinedStatus = serde_json::from_str(
+            r#"{
+                "state": "", "sha": "", "total_count": 0, "statuses": null,
+                "repository": null, "commit_url": "", "url": ""
+            }"#,
+        )
+        .unwrap();
+
+        assert!(status.state.is_none());
+        assert!(status.statuses.is_empty());
+        assert!(status.repository.is_none());
+    }
+
+    #[test]
+    fn merge_style_serialize() {
+        let objs = [
+            MergeStyle::Merge,
+            MergeStyle::Rebase,
+            MergeStyle::RebaseMerge,
+            MergeStyle::Squash,
+            MergeStyle::ManuallyMerged,
+        ];
+        let reprs = [
+            "\"merge\"",
+            "\"rebase\"",
+            "\"rebase-merge\"",
+            "\"squash\"",
+            "\"manually-merged\"",
+        ];
+
+        for (obj, repr) in objs.iter().zip(reprs.iter()) {
+            let res_repr = serde_json::to_string(obj).unwrap();
+            assert_eq!(&res_repr, repr);
+        }
+    }
+
+    #[test]
+    fn migrate_repo_options_debug_redacts_credentials() {
+        let mut opt = MigrateRepoOptions::new(
+            "https://github.com/foo/bar",
+            "foo",
+            "bar",
+            GitServiceType::Github,
+        );
+        opt.auth_password = Some("secret".to_string());
+        opt.auth_token = Some("secret".to_string());
+
+        let repr = format!("{:?}", opt);
+        assert!(!repr.contains("secret"));
+        assert!(repr.contains("auth_token: Some(<redacted>)"));
+    }
 }
@@ -24,3 +24,36 @@ pub struct User {
     pub visibility: String,
     pub website: String,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserSearchResult {
+    pub data: Vec<User>,
+    pub ok: bool,
+}
+
+/// UserHeatmapData represents the contributions of a user in a time slot
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserHeatmapData {
+    /// Unix timestamp
+    pub timestamp: i64,
+    pub contributions: i64,
+}
+
+/// PersonalAccessToken represents an API access token
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PersonalAccessToken {
+    pub id: i64,
+    pub name: String,
+    /// The token itself, only returned once on creation
+    pub sha1: String,
+    pub token_last_eight: String,
+    pub scopes: Option<Vec<String>>,
+}
+
+/// Options when create an access token
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct CreateAccessTokenOption {
+    pub name: String,
+    /// E.g. `read:repository` or `write:issue`
+    pub scopes: Vec<String>,
+}